@@ -0,0 +1,41 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, math::Float, ops::noise_ops};
+
+/// A noise function which takes the absolute value of another noise function.
+///
+/// `noise(x) = |inner.noise(x)|`
+#[derive(Copy, Clone)]
+pub struct Abs<Inner> {
+	inner: Inner,
+}
+
+noise_ops!([Inner,] Abs<Inner>);
+
+impl<Inner: Noise> Noise for Abs<Inner> where Inner::Value: Float {
+	type Value = Inner::Value;
+	type Unseeded = Abs<Inner::Unseeded>;
+}
+
+impl<Inner> Abs<Inner> {
+	pub fn new(inner: Inner) -> Abs<Inner> {
+		Abs { inner }
+	}
+}
+
+impl<Inner: Seedable> Seedable for Abs<Inner> {
+	type Seed = Inner::Seed;
+	type Seeded = Abs<Inner::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Abs { inner: self.inner.seed(seed) }
+	}
+}
+
+impl<Inner: Seeded> Seeded for Abs<Inner> {
+	type Config = Abs<Inner::Config>;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg>> NoiseDomain<Arg> for Abs<Inner> where Inner::Value: Float {
+	fn noise(&self, arg: Arg) -> Self::Value {
+		self.inner.noise(arg).abs()
+	}
+}
@@ -1,4 +1,10 @@
-use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}};
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
+
+#[cfg(feature = "simd")]
+use higher_order_functions::Zip;
 
 use core::ops::Add;
 
@@ -11,6 +17,8 @@ pub struct AddNoise<Inner1, Inner2> {
 	inner2: Inner2,
 }
 
+noise_ops!([Inner1, Inner2,] AddNoise<Inner1, Inner2>);
+
 impl<Inner1: Noise, Inner2: Noise> Noise for AddNoise<Inner1, Inner2> where Inner1::Value: Add<Inner2::Value> {
 	type Value = <Inner1::Value as Add<Inner2::Value>>::Output;
 	type Unseeded = AddNoise<Inner1::Unseeded, Inner2::Unseeded>;
@@ -66,4 +74,30 @@ impl<Arg: Copy, Inner1: NoiseDomain<Arg>, Inner2: NoiseDomain<Arg>> NoiseDomain<
 	fn noise(&self, arg: Arg) -> Self::Value {
 		self.inner1.noise(arg) + self.inner2.noise(arg)
 	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner1.noise_points(points.clone()).zip(self.inner2.noise_points(points)).map(|(value1, value2)| value1 + value2)
+	}
+}
+
+impl<Arg: Copy, Inner1: NoiseDerivative<Arg>, Inner2: NoiseDerivative<Arg>> NoiseDerivative<Arg> for AddNoise<Inner1, Inner2> where
+	Inner1::Value: Add<Inner2::Value>,
+	Inner1::Derivative: Add<Inner2::Derivative>,
+{
+	type Derivative = <Inner1::Derivative as Add<Inner2::Derivative>>::Output;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let (value1, derivative1) = self.inner1.noise_derivative(arg);
+		let (value2, derivative2) = self.inner2.noise_derivative(arg);
+		(value1 + value2, derivative1 + derivative2)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg: Copy, Inner1: NoiseBatch<Arg>, Inner2: NoiseBatch<Arg>> NoiseBatch<Arg> for AddNoise<Inner1, Inner2> where
+	Inner1::Value: Add<Inner2::Value>,
+{
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		self.inner1.noise_batch(args).zip_with(self.inner2.noise_batch(args), |value1, value2| value1 + value2)
+	}
 }
@@ -0,0 +1,55 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}, layers::Layers, math::Float, ops::noise_ops};
+
+use core::ops::Mul;
+
+/// A noise function formed by summing several octaves of the absolute value of another noise function, giving a billowy, cloud-like look.
+///
+/// `noise(x) = sum(persistence^i * (2 * |inner.noise(x * lacunarity^i)| - 1))`
+///
+/// `lacunarity`: A multiplier for the frequency from one octave to the next.
+///
+/// `persistence`: A multiplier for the amplitude from one octave to the next.
+///
+/// A good default is `Billow::new(_, 2.0, 0.5)`.
+///
+/// `T` is the floating point type of the parameters and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct Billow<Inner, const N: usize, T = f64> {
+	layers: Layers<Inner, N, T>,
+}
+
+noise_ops!([Inner, const N: usize, T,] Billow<Inner, N, T>);
+
+impl<Inner: Noise, const N: usize, T: Float> Noise for Billow<Inner, N, T> {
+	type Value = T;
+	type Unseeded = Billow<Inner::Unseeded, N, T>;
+}
+
+impl<Inner: Copy, const N: usize, T: Float> Billow<Inner, N, T> {
+	pub fn new(inner: Inner, lacunarity: T, persistence: T) -> Billow<Inner, N, T> {
+		Billow { layers: Layers::new(inner, lacunarity, persistence) }
+	}
+}
+
+impl<Inner: Seedable, const N: usize, T> Seedable for Billow<Inner, N, T> where Inner::Seed: SplitSeed {
+	type Seed = Inner::Seed;
+	type Seeded = Billow<Inner::Seeded, N, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Billow { layers: self.layers.seed(seed) }
+	}
+}
+
+impl<Inner: Seeded, const N: usize, T> Seeded for Billow<Inner, N, T> where <Inner::Config as Seedable>::Seed: SplitSeed {
+	type Config = Billow<Inner::Config, N, T>;
+}
+
+impl<Arg: Copy + Mul<T>, Inner: NoiseDomain<Arg::Output, Value = T>, const N: usize, T: Float> NoiseDomain<Arg> for Billow<Inner, N, T> {
+	fn noise(&self, arg: Arg) -> T {
+		let mut sum = T::zero();
+		for (value, amplitude) in self.layers.octaves(arg) {
+			sum += (T::from_f64(2.) * value.abs() - T::one()) * amplitude;
+		}
+		sum
+	}
+}
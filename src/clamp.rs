@@ -0,0 +1,52 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, math::Float, ops::noise_ops};
+
+/// A noise function which clamps the values of another noise function to a range.
+///
+/// `noise(x) = min(max(inner.noise(x), min), max)`
+#[derive(Copy, Clone)]
+pub struct Clamp<Inner, T = f64> {
+	inner: Inner,
+	min: T,
+	max: T,
+}
+
+noise_ops!([Inner, T,] Clamp<Inner, T>);
+
+impl<Inner: Noise<Value = T>, T: Float> Noise for Clamp<Inner, T> {
+	type Value = T;
+	type Unseeded = Clamp<Inner::Unseeded, T>;
+}
+
+impl<Inner, T: Float> Clamp<Inner, T> {
+	/// Panics if `min` is greater than `max`, or either is NaN.
+	pub fn new(inner: Inner, min: T, max: T) -> Clamp<Inner, T> {
+		assert!(min <= max, "min must not be greater than max");
+		Clamp { inner, min, max }
+	}
+}
+
+impl<Inner: Seedable, T> Seedable for Clamp<Inner, T> {
+	type Seed = Inner::Seed;
+	type Seeded = Clamp<Inner::Seeded, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Clamp { inner: self.inner.seed(seed), min: self.min, max: self.max }
+	}
+}
+
+impl<Inner: Seeded, T> Seeded for Clamp<Inner, T> {
+	type Config = Clamp<Inner::Config, T>;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg, Value = T>, T: Float> NoiseDomain<Arg> for Clamp<Inner, T> {
+	fn noise(&self, arg: Arg) -> T {
+		let value = self.inner.noise(arg);
+		if value < self.min {
+			self.min
+		} else if value > self.max {
+			self.max
+		} else {
+			value
+		}
+	}
+}
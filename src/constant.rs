@@ -1,4 +1,6 @@
-use super::{Noise, NoiseDomain};
+use super::{Noise, NoiseDomain, noise::NoiseDerivative, ops::noise_ops};
+
+use num_traits::Zero;
 
 /// A noise function returning a constant value.
 #[derive(Copy, Clone)]
@@ -6,6 +8,8 @@ pub struct Constant<Value: Copy> {
 	value: Value,
 }
 
+noise_ops!([Value: Copy,] Constant<Value>);
+
 impl<Value: Copy> Constant<Value> {
 	pub fn new(value: Value) -> Self {
 		Self { value }
@@ -22,3 +26,11 @@ impl<Arg, Value: Copy> NoiseDomain<Arg> for Constant<Value> {
 		self.value
 	}
 }
+
+impl<Arg: Zero, Value: Copy> NoiseDerivative<Arg> for Constant<Value> {
+	type Derivative = Arg;
+	
+	fn noise_derivative(&self, _: Arg) -> (Self::Value, Self::Derivative) {
+		(self.value, Arg::zero())
+	}
+}
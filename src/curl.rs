@@ -0,0 +1,83 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, math::Float, ops::noise_ops};
+
+use sized_matrix::Vector;
+
+use higher_order_functions::{Zip, Init};
+
+use core::marker::PhantomData;
+
+/// A divergence-free vector field formed by taking the curl of a potential.
+///
+/// `CurlNoise<Inner, 1>` takes a scalar potential `p` and produces a 2D field `(dp/dy, -dp/dx)`.
+///
+/// `CurlNoise<Inner, 3>` takes a vector potential with each component seeded separately and produces the 3D field `curl(p)`.
+///
+/// The potentials must implement [`NoiseDerivative`].
+///
+/// `T` is the floating point type used for positions and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct CurlNoise<Inner, const M: usize, T = f64> {
+	potentials: [Inner; M],
+	_phantom: PhantomData<fn() -> T>,
+}
+
+noise_ops!(unscaled [Inner, const M: usize, T,] CurlNoise<Inner, M, T>);
+
+impl<Inner: Noise, T: Float> Noise for CurlNoise<Inner, 1, T> {
+	type Value = Vector<T, 2>;
+	type Unseeded = CurlNoise<Inner::Unseeded, 1, T>;
+}
+
+impl<Inner: Noise, T: Float> Noise for CurlNoise<Inner, 3, T> {
+	type Value = Vector<T, 3>;
+	type Unseeded = CurlNoise<Inner::Unseeded, 3, T>;
+}
+
+impl<Inner: Copy, const M: usize, T> CurlNoise<Inner, M, T> {
+	pub fn new(potential: Inner) -> CurlNoise<Inner, M, T> {
+		CurlNoise::from_potentials([potential; M])
+	}
+}
+
+impl<Inner, const M: usize, T> CurlNoise<Inner, M, T> {
+	pub fn from_potentials(potentials: [Inner; M]) -> CurlNoise<Inner, M, T> {
+		CurlNoise { potentials, _phantom: PhantomData }
+	}
+}
+
+impl<Inner: Seedable, const M: usize, T> Seedable for CurlNoise<Inner, M, T> where Inner::Seed: SplitSeed {
+	type Seed = Inner::Seed;
+	type Seeded = CurlNoise<Inner::Seeded, M, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		CurlNoise::from_potentials(self.potentials.zip_with(Init::init(|i| i), |c, i| c.seed(seed.split(i))))
+	}
+}
+
+impl<Inner: Seeded, const M: usize, T> Seeded for CurlNoise<Inner, M, T> where <Inner::Config as Seedable>::Seed: SplitSeed {
+	type Config = CurlNoise<Inner::Config, M, T>;
+}
+
+impl<Inner, T: Float> NoiseDomain<Vector<T, 2>> for CurlNoise<Inner, 1, T> where
+	Inner: NoiseDerivative<Vector<T, 2>, Value = T, Derivative = Vector<T, 2>>,
+{
+	fn noise(&self, pos: Vector<T, 2>) -> Vector<T, 2> {
+		let (_, d) = self.potentials[0].noise_derivative(pos);
+		Vector::vector([d[1], -d[0]])
+	}
+}
+
+impl<Inner, T: Float> NoiseDomain<Vector<T, 3>> for CurlNoise<Inner, 3, T> where
+	Inner: NoiseDerivative<Vector<T, 3>, Value = T, Derivative = Vector<T, 3>>,
+{
+	fn noise(&self, pos: Vector<T, 3>) -> Vector<T, 3> {
+		let (_, dx) = self.potentials[0].noise_derivative(pos);
+		let (_, dy) = self.potentials[1].noise_derivative(pos);
+		let (_, dz) = self.potentials[2].noise_derivative(pos);
+		Vector::vector([
+			dz[1] - dy[2],
+			dx[2] - dz[0],
+			dy[0] - dx[1],
+		])
+	}
+}
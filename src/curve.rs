@@ -0,0 +1,97 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, math::Float, ops::noise_ops};
+
+/// A noise function which remaps the values of another noise function through a curve.
+///
+/// The curve is a monotone cubic spline through the control points, so it never overshoots them: if the outputs of the
+/// control points are increasing then so is the curve.
+///
+/// `points`: The `(input, output)` pairs the curve passes through, in any order. Inputs outside their range give the
+/// output of the first or last point.
+#[derive(Copy, Clone)]
+pub struct Curve<Inner, const N: usize, T = f64> {
+	inner: Inner,
+	points: [(T, T); N],
+	tangents: [T; N],
+}
+
+noise_ops!([Inner, const N: usize, T,] Curve<Inner, N, T>);
+
+impl<Inner: Noise<Value = T>, const N: usize, T: Float> Noise for Curve<Inner, N, T> {
+	type Value = T;
+	type Unseeded = Curve<Inner::Unseeded, N, T>;
+}
+
+impl<Inner, const N: usize, T: Float> Curve<Inner, N, T> {
+	/// Panics if there are fewer than two points, any input is NaN, or two points have the same input.
+	pub fn new(inner: Inner, mut points: [(T, T); N]) -> Curve<Inner, N, T> {
+		assert!(N >= 2, "a curve needs at least two control points");
+		points.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).expect("control points must not be NaN"));
+		assert!(points.windows(2).all(|pair| pair[0].0 < pair[1].0), "control points must have distinct inputs");
+		
+		// Fritsch-Carlson: start from the average of the neighbouring secants, then limit the tangents to keep each
+		// segment monotone
+		let secant = |k: usize| (points[k + 1].1 - points[k].1) / (points[k + 1].0 - points[k].0);
+		let mut tangents: [T; N] = core::array::from_fn(|k| match k {
+			0 => secant(0),
+			k if k == N - 1 => secant(N - 2),
+			k if secant(k - 1) * secant(k) > T::zero() => (secant(k - 1) + secant(k)) / T::from_f64(2.),
+			_ => T::zero(),
+		});
+		for k in 0..N - 1 {
+			let d = secant(k);
+			if d == T::zero() {
+				tangents[k] = T::zero();
+				tangents[k + 1] = T::zero();
+			} else {
+				let (a, b) = (tangents[k] / d, tangents[k + 1] / d);
+				let length = a * a + b * b;
+				if length > T::from_f64(9.) {
+					let t = T::from_f64(3.) / length.sqrt();
+					tangents[k] = t * a * d;
+					tangents[k + 1] = t * b * d;
+				}
+			}
+		}
+		
+		Curve { inner, points, tangents }
+	}
+}
+
+impl<Inner: Seedable, const N: usize, T> Seedable for Curve<Inner, N, T> {
+	type Seed = Inner::Seed;
+	type Seeded = Curve<Inner::Seeded, N, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Curve { inner: self.inner.seed(seed), points: self.points, tangents: self.tangents }
+	}
+}
+
+impl<Inner: Seeded, const N: usize, T> Seeded for Curve<Inner, N, T> {
+	type Config = Curve<Inner::Config, N, T>;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg, Value = T>, const N: usize, T: Float> NoiseDomain<Arg> for Curve<Inner, N, T> {
+	fn noise(&self, arg: Arg) -> T {
+		let value = self.inner.noise(arg);
+		let upper = match self.points.iter().position(|&(input, _)| value < input) {
+			Some(0) => return self.points[0].1,
+			Some(upper) => upper,
+			None => return self.points[N - 1].1,
+		};
+		
+		let ((x0, y0), (x1, y1)) = (self.points[upper - 1], self.points[upper]);
+		let (m0, m1) = (self.tangents[upper - 1], self.tangents[upper]);
+		let h = x1 - x0;
+		let t = (value - x0) / h;
+		let (two, three) = (T::from_f64(2.), T::from_f64(3.));
+		
+		// Cubic Hermite basis
+		let t2 = t * t;
+		let t3 = t2 * t;
+		let h00 = two * t3 - three * t2 + T::one();
+		let h10 = t3 - two * t2 + t;
+		let h01 = three * t2 - two * t3;
+		let h11 = t3 - t2;
+		h00 * y0 + h10 * h * m0 + h01 * y1 + h11 * h * m1
+	}
+}
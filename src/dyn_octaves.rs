@@ -0,0 +1,84 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, DynSumNoise, ScaleNoise, math::{Float, powi}, ops::noise_ops};
+
+use core::ops::{Mul, AddAssign};
+use num_traits::Zero;
+
+/// A noise function formed by summing a runtime-sized number of octaves of another noise function.
+///
+/// This is seeded in the same way as [`Octaves`](crate::Octaves), so `DynOctaves::new(_, N, lacunarity, persistence)` gives the same results as `Octaves::<_, N>::new(_, lacunarity, persistence)`.
+///
+/// `T` is the floating point type of the scales, i.e. `f64` (the default) or `f32`.
+#[derive(Clone)]
+pub struct DynOctaves<Inner, T: Copy = f64> {
+	inner: DynSumNoise<ScaleNoise<Inner, T, T>>,
+}
+
+noise_ops!([Inner, T: Copy,] DynOctaves<Inner, T>);
+
+impl<Inner: Noise, T: Copy> Noise for DynOctaves<Inner, T> where
+	Inner::Value: Mul<T>,
+	<Inner::Value as Mul<T>>::Output: Zero + AddAssign,
+{
+	type Value = <DynSumNoise<ScaleNoise<Inner, T, T>> as Noise>::Value;
+	type Unseeded = DynOctaves<Inner::Unseeded, T>;
+}
+
+impl<Inner: Clone, T: Float> DynOctaves<Inner, T> {
+	/// Create `octaves` octaves, multiplying the frequency by `lacunarity` and the amplitude by `persistence` from one octave to the next.
+	pub fn new(inner: Inner, octaves: usize, lacunarity: T, persistence: T) -> DynOctaves<Inner, T> {
+		Self::from_layers(inner, (0..octaves).map(|i| (powi(lacunarity, i as i32), powi(persistence, i as i32))))
+	}
+	
+	/// Create one octave for each `(frequency, amplitude)` pair.
+	pub fn from_layers<Layers: IntoIterator<Item = (T, T)>>(inner: Inner, layers: Layers) -> DynOctaves<Inner, T> {
+		DynOctaves { inner: DynSumNoise::new(layers.into_iter().map(|(frequency, amplitude)| ScaleNoise::new(inner.clone(), frequency, amplitude)).collect()) }
+	}
+}
+
+impl<Inner: Seedable, T: Copy> Seedable for DynOctaves<Inner, T> where
+	Inner::Seed: SplitSeed,
+{
+	type Seed = <DynSumNoise<ScaleNoise<Inner, T, T>> as Seedable>::Seed;
+	type Seeded = DynOctaves<Inner::Seeded, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		DynOctaves { inner: self.inner.seed(seed) }
+	}
+}
+
+impl<Inner: Seeded, T: Copy> Seeded for DynOctaves<Inner, T> where
+	<Inner::Config as Seedable>::Seed: SplitSeed,
+{
+	type Config = DynOctaves<Inner::Config, T>;
+}
+
+impl<Arg: Copy, Inner: Noise, T: Copy> NoiseDomain<Arg> for DynOctaves<Inner, T> where
+	Arg: Mul<T>,
+	Inner: NoiseDomain<Arg::Output>,
+	Inner::Value: Mul<T>,
+	<Inner::Value as Mul<T>>::Output: Zero + AddAssign,
+{
+	fn noise(&self, arg: Arg) -> Self::Value {
+		self.inner.noise(arg)
+	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points)
+	}
+}
+
+impl<Arg: Copy, Inner: Noise, T: Copy> NoiseDerivative<Arg> for DynOctaves<Inner, T> where
+	Arg: Mul<T>,
+	Inner: NoiseDerivative<Arg::Output>,
+	Inner::Value: Mul<T>,
+	<Inner::Value as Mul<T>>::Output: Zero + AddAssign,
+	Inner::Derivative: Mul<T>,
+	<Inner::Derivative as Mul<T>>::Output: Mul<T>,
+	<<Inner::Derivative as Mul<T>>::Output as Mul<T>>::Output: Zero + AddAssign,
+{
+	type Derivative = <DynSumNoise<ScaleNoise<Inner, T, T>> as NoiseDerivative<Arg>>::Derivative;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		self.inner.noise_derivative(arg)
+	}
+}
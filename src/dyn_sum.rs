@@ -0,0 +1,79 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, ops::noise_ops};
+
+use alloc::vec::Vec;
+
+use core::ops::AddAssign;
+use num_traits::Zero;
+
+/// A noise function formed by summing a runtime-sized list of other noise functions.
+///
+/// This is seeded in the same way as [`SumNoise`](crate::SumNoise).
+#[derive(Clone)]
+pub struct DynSumNoise<Inner> {
+	inners: Vec<Inner>,
+}
+
+noise_ops!([Inner,] DynSumNoise<Inner>);
+
+impl<Inner: Noise> Noise for DynSumNoise<Inner> where Inner::Value: Zero + AddAssign {
+	type Value = Inner::Value;
+	type Unseeded = DynSumNoise<Inner::Unseeded>;
+}
+
+impl<Inner> DynSumNoise<Inner> {
+	pub fn new(inners: Vec<Inner>) -> DynSumNoise<Inner> {
+		DynSumNoise { inners }
+	}
+}
+
+impl<Inner: Seedable> Seedable for DynSumNoise<Inner> where Inner::Seed: SplitSeed {
+	type Seed = Inner::Seed;
+	type Seeded = DynSumNoise<Inner::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		DynSumNoise { inners: self.inners.into_iter().enumerate().map(|(i, c)| c.seed(seed.split(i))).collect() }
+	}
+}
+
+impl<Inner: Seeded> Seeded for DynSumNoise<Inner> where <Inner::Config as Seedable>::Seed: SplitSeed {
+	type Config = DynSumNoise<Inner::Config>;
+}
+
+impl<Arg: Copy, Inner: NoiseDomain<Arg>> NoiseDomain<Arg> for DynSumNoise<Inner> where Inner::Value: Zero + AddAssign {
+	fn noise(&self, arg: Arg) -> Self::Value {
+		let mut sum = Inner::Value::zero();
+		for inner in &self.inners {
+			sum += inner.noise(arg);
+		}
+		sum
+	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		let mut inners: Vec<_> = self.inners.iter().map(|inner| inner.noise_points(points.clone())).collect();
+		points.map(move |_| {
+			let mut sum = Inner::Value::zero();
+			for values in &mut inners {
+				sum += values.next().expect("noise_points gave too few values");
+			}
+			sum
+		})
+	}
+}
+
+impl<Arg: Copy, Inner: NoiseDerivative<Arg>> NoiseDerivative<Arg> for DynSumNoise<Inner> where
+	Inner::Value: Zero + AddAssign,
+	Inner::Derivative: Zero + AddAssign,
+{
+	type Derivative = Inner::Derivative;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let mut sum = Inner::Value::zero();
+		let mut derivative_sum = Inner::Derivative::zero();
+		for inner in &self.inners {
+			let (value, derivative) = inner.noise_derivative(arg);
+			sum += value;
+			derivative_sum += derivative;
+		}
+		(sum, derivative_sum)
+	}
+}
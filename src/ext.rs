@@ -0,0 +1,60 @@
+use super::{NoiseOperand, ScaleNoise, AddNoise, Octaves, ToFloat, Warp, MapNoise, MapArg, math::Float};
+
+/// Chainable methods for building noise functions out of the combinator types, so noise graphs read top-to-bottom.
+///
+/// These work on both configurations and seeded noise functions, and are the same as calling the combinator's `new`.
+/// This is implemented for every [`NoiseOperand`], so it doesn't add methods to other types.
+///
+/// `add` is ambiguous with [`Add::add`](core::ops::Add::add) if that trait is imported, since noise functions also implement
+/// `+`. Use `a + b` instead in that case, which gives the same [`AddNoise`].
+///
+/// ```rust
+/// use noise_fn::{Seedable, NoiseDomain, NoiseExt, Simplex};
+/// use sized_matrix::Vector;
+///
+/// let noise = Simplex::new()
+///     .octaves::<4>(0.5, 0.5)
+///     .scale(2.0, 1.0)
+///     .add(Simplex::new().scale(0.1, 0.3))
+///     .seed(12345);
+///
+/// let value = noise.noise(Vector::vector([1.2, -3.5]));
+/// ```
+pub trait NoiseExt: Sized {
+	/// Scale the input and output values, as in [`ScaleNoise`].
+	fn scale<InScale: Copy, OutScale: Copy>(self, scale_in: InScale, scale_out: OutScale) -> ScaleNoise<Self, InScale, OutScale> {
+		ScaleNoise::new(self, scale_in, scale_out)
+	}
+	
+	/// Sum with another noise function, as in [`AddNoise`].
+	fn add<Other>(self, other: Other) -> AddNoise<Self, Other> {
+		AddNoise::new(self, other)
+	}
+	
+	/// Sum `N` octaves, as in [`Octaves`].
+	fn octaves<const N: usize>(self, lacunarity: f64, persistence: f64) -> Octaves<Self, N> where Self: Copy {
+		Octaves::new(self, lacunarity, persistence)
+	}
+	
+	/// Convert random uint values to floating point numbers, as in [`ToFloat`].
+	fn to_float(self) -> ToFloat<Self> {
+		ToFloat::new(self)
+	}
+	
+	/// Distort the input using another noise function, as in [`Warp`].
+	fn warp<Offset, T: Float>(self, offset: Offset, strength: T) -> Warp<Self, Offset, T> {
+		Warp::new(self, offset, strength)
+	}
+	
+	/// Transform the values using a closure, as in [`MapNoise`].
+	fn map<F>(self, f: F) -> MapNoise<Self, F> {
+		MapNoise::new(self, f)
+	}
+	
+	/// Transform the input using a closure, as in [`MapArg`].
+	fn map_arg<F>(self, f: F) -> MapArg<Self, F> {
+		MapArg::new(self, f)
+	}
+}
+
+impl<T: NoiseOperand> NoiseExt for T { }
@@ -0,0 +1,19 @@
+//! Helpers for Q16.16 fixed-point arithmetic.
+//!
+//! Intermediate values are kept as `i64`s to avoid overflow.
+
+/// The number of fractional bits.
+pub const FRAC_BITS: u32 = 16;
+
+/// The fixed-point representation of 1.
+pub const ONE: i64 = 1 << FRAC_BITS;
+
+/// Multiply two fixed-point numbers.
+pub fn mul(a: i64, b: i64) -> i64 {
+	(a * b) >> FRAC_BITS
+}
+
+/// Convert an intermediate value back to an `i32`, saturating if it is out of range.
+pub fn to_i32(x: i64) -> i32 {
+	x.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
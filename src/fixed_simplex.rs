@@ -0,0 +1,125 @@
+use super::{NoiseDomain, HashNoise, Simplex, helpers::{SeedOnlyNoise, EmptyConfig}, fixed::{FRAC_BITS, ONE, to_i32}, simplex::{GRAD3_INT, GRAD4_INT, simplex_ranks, simplex_offset}};
+
+use sized_matrix::Vector;
+
+use higher_order_functions::{Init, Map, Zip};
+
+impl Simplex<i32> {
+	/// Like `Simplex::new`, but evaluated in fixed point without using any floating point arithmetic, for 2D, 3D, and 4D.
+	///
+	/// Positions and values are Q16.16 fixed-point numbers, i.e. `x` is represented by `(x * 65536.) as i32`.
+	/// The values are approximately in the range `[-65536, 65536]`, and are within about `0.0005` of those of `Simplex<f64>` with the same seed.
+	pub fn new_fixed() -> EmptyConfig<Self> {
+		EmptyConfig::new()
+	}
+}
+
+impl SeedOnlyNoise for Simplex<i32> {
+	type Seed = u64;
+	type Value = i32;
+	
+	fn seed(seed: u64) -> Self {
+		Simplex::from_hash(HashNoise::seed(seed))
+	}
+}
+
+// The skew and unskew factors, as Q32.32 so they stay accurate far from the origin.
+const F2: i64 = 1572067139;
+const G2: i64 = 907633386;
+const F3: i64 = 1431655765;
+const G3: i64 = 715827883;
+const F4: i64 = 1327217885;
+const G4: i64 = 593549882;
+
+const R_SQUARED_2: i64 = ONE / 2;
+const R_SQUARED_34: i64 = 39322;
+
+fn dot<const N: usize>(a: Vector<i64, N>, b: Vector<i64, N>) -> i64 {
+	let mut result = 0;
+	for i in 0..N {
+		result += a[i] * b[i];
+	}
+	result
+}
+
+fn skew<const N: usize>(pos: Vector<i32, N>, f: i64, g: i64) -> (Vector<i64, N>, Vector<i64, N>) {
+	let pos = pos.map(i64::from);
+	let mut sum = 0;
+	for i in 0..N {
+		sum += pos[i];
+	}
+	
+	let s = ((sum as i128 * f as i128) >> 32) as i64;
+	let base = pos.map(|x| (x + s) >> FRAC_BITS);
+	
+	let mut base_sum = 0;
+	for i in 0..N {
+		base_sum += base[i];
+	}
+	
+	let t = (base_sum * g) >> 16;
+	let rel = pos.zip_with(base, |p, b| p - (b << FRAC_BITS) + t);
+	
+	(base, rel)
+}
+
+fn vertex_rel<const N: usize>(rel: Vector<i64, N>, offset: Vector<i64, N>, g: i64) -> Vector<i64, N> {
+	rel.zip_with(offset, |r, o| r - (o << FRAC_BITS) + (g >> 16))
+}
+
+fn gradient<const N: usize, const M: usize>(inner: &HashNoise, vertex: Vector<i64, N>, grads: &[[i8; M]]) -> Vector<i64, N> {
+	let grad = grads[(inner.noise(vertex) % grads.len() as u64) as usize];
+	Vector::init(|i: usize| i64::from(grad[i]))
+}
+
+// Returns the contribution as Q16.48 to keep the precision of `t^4`.
+fn factor<const N: usize, Grad: FnOnce() -> Vector<i64, N>>(r_squared: i64, rel: Vector<i64, N>, grad: Grad) -> i64 {
+	let t = r_squared - (dot(rel, rel) >> FRAC_BITS);
+	if t < 0 { 0 }
+	else {
+		let t2 = t * t;
+		((t2 * t2) >> 32) * dot(rel, grad())
+	}
+}
+
+impl NoiseDomain<Vector<i32, 2>> for Simplex<i32> {
+	fn noise(&self, pos: Vector<i32, 2>) -> i32 {
+		let (base, rel) = skew(pos, F2, G2);
+		let ranks = simplex_ranks(rel);
+		
+		let mut value = 0;
+		for i in 0..=2 {
+			let offset = simplex_offset(ranks, i);
+			value += factor(R_SQUARED_2, vertex_rel(rel, offset, i as i64 * G2), || gradient(self.hash(), base + offset, &GRAD3_INT));
+		}
+		to_i32((70 * value) >> 32)
+	}
+}
+
+impl NoiseDomain<Vector<i32, 3>> for Simplex<i32> {
+	fn noise(&self, pos: Vector<i32, 3>) -> i32 {
+		let (base, rel) = skew(pos, F3, G3);
+		let ranks = simplex_ranks(rel);
+		
+		let mut value = 0;
+		for i in 0..=3 {
+			let offset = simplex_offset(ranks, i);
+			value += factor(R_SQUARED_34, vertex_rel(rel, offset, i as i64 * G3), || gradient(self.hash(), base + offset, &GRAD3_INT));
+		}
+		to_i32((32 * value) >> 32)
+	}
+}
+
+impl NoiseDomain<Vector<i32, 4>> for Simplex<i32> {
+	fn noise(&self, pos: Vector<i32, 4>) -> i32 {
+		let (base, rel) = skew(pos, F4, G4);
+		let ranks = simplex_ranks(rel);
+		
+		let mut value = 0;
+		for i in 0..=4 {
+			let offset = simplex_offset(ranks, i);
+			value += factor(R_SQUARED_34, vertex_rel(rel, offset, i as i64 * G4), || gradient(self.hash(), base + offset, &GRAD4_INT));
+		}
+		to_i32((27 * value) >> 32)
+	}
+}
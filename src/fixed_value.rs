@@ -0,0 +1,88 @@
+use super::{Noise, NoiseDomain, Seedable, HashNoise, Interpolation, noise::Seeded, helpers::EmptyConfig, fixed::{FRAC_BITS, ONE, mul, to_i32}, ops::noise_ops};
+
+use sized_matrix::Vector;
+
+use higher_order_functions::{Init, Map, Zip};
+
+/// A fixed-point version of [`ValueNoise`](super::ValueNoise) which never uses floating point arithmetic.
+///
+/// Positions and values are Q16.16 fixed-point numbers, i.e. `x` is represented by `(x * 65536.) as i32`.
+/// The values are in the range `[-65536, 65536)`.
+#[derive(Copy, Clone)]
+pub struct FixedValueNoise<Inner> {
+	inner: Inner,
+	interpolation: Interpolation,
+}
+
+noise_ops!(unscaled [Inner,] FixedValueNoise<Inner>);
+
+impl FixedValueNoise<EmptyConfig<HashNoise>> {
+	pub fn new(interpolation: Interpolation) -> Self {
+		FixedValueNoise { inner: HashNoise::new(), interpolation }
+	}
+}
+
+impl<Inner: Noise> Noise for FixedValueNoise<Inner> {
+	type Value = i32;
+	type Unseeded = FixedValueNoise<Inner::Unseeded>;
+}
+
+impl<Inner: Seedable> Seedable for FixedValueNoise<Inner> {
+	type Seed = Inner::Seed;
+	type Seeded = FixedValueNoise<Inner::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		FixedValueNoise { inner: self.inner.seed(seed), interpolation: self.interpolation }
+	}
+}
+
+impl<Inner: Seeded> Seeded for FixedValueNoise<Inner> {
+	type Config = FixedValueNoise<Inner::Config>;
+}
+
+impl<Inner> FixedValueNoise<Inner> {
+	fn value<const N: usize>(&self, pos: Vector<i32, N>) -> i32 where Inner: NoiseDomain<Vector<i64, N>, Value = u64> {
+		let pos = pos.map(i64::from);
+		let base = pos.map(|x| x >> FRAC_BITS);
+		let weights = pos.zip_with(base, |p, b| self.interpolation.curve_fixed(p - (b << FRAC_BITS)));
+		
+		let mut sum = 0;
+		for corner in 0..(1usize << N) {
+			let offset = Vector::<i64, N>::init(|i: usize| ((corner >> i) & 1) as i64);
+			
+			let mut weight = ONE;
+			for i in 0..N {
+				weight = mul(weight, if offset[i] == 1 { weights[i] } else { ONE - weights[i] });
+			}
+			
+			// The top 17 bits give a value in [0, 2).
+			let value = (self.inner.noise(base + offset) >> 47) as i64 - ONE;
+			sum += mul(weight, value);
+		}
+		to_i32(sum)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 1>, Value = u64>> NoiseDomain<Vector<i32, 1>> for FixedValueNoise<Inner> {
+	fn noise(&self, pos: Vector<i32, 1>) -> i32 {
+		self.value(pos)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 2>, Value = u64>> NoiseDomain<Vector<i32, 2>> for FixedValueNoise<Inner> {
+	fn noise(&self, pos: Vector<i32, 2>) -> i32 {
+		self.value(pos)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 3>, Value = u64>> NoiseDomain<Vector<i32, 3>> for FixedValueNoise<Inner> {
+	fn noise(&self, pos: Vector<i32, 3>) -> i32 {
+		self.value(pos)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 4>, Value = u64>> NoiseDomain<Vector<i32, 4>> for FixedValueNoise<Inner> {
+	fn noise(&self, pos: Vector<i32, 4>) -> i32 {
+		self.value(pos)
+	}
+}
@@ -0,0 +1,100 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, ops::noise_ops};
+
+use core::marker::PhantomData;
+
+/// A noise function defined by a closure taking `Arg`s.
+///
+/// This can be seeded with any `Seed` (`u64` by default) to use it alongside seedable noise functions, but the seed is ignored.
+/// Use [`SeedFnNoise`] for closures which depend on the seed.
+#[derive(Copy, Clone)]
+pub struct FnNoise<F, Arg, Seed = u64> {
+	f: F,
+	_phantom: PhantomData<fn(Seed, Arg)>,
+}
+
+noise_ops!([F, Arg, Seed,] FnNoise<F, Arg, Seed>);
+
+impl<F: Fn(Arg) -> Value, Arg, Value, Seed> Noise for FnNoise<F, Arg, Seed> {
+	type Value = Value;
+}
+
+impl<F, Arg> FnNoise<F, Arg> {
+	pub fn new<Value>(f: F) -> FnNoise<F, Arg> where F: Fn(Arg) -> Value {
+		FnNoise { f, _phantom: PhantomData }
+	}
+}
+
+impl<F, Arg, Seed> Seedable for FnNoise<F, Arg, Seed> {
+	type Seed = Seed;
+	type Seeded = Self;
+	
+	fn seed(self, _: Seed) -> Self {
+		self
+	}
+}
+
+impl<F, Arg, Seed> Seeded for FnNoise<F, Arg, Seed> {
+	type Config = Self;
+}
+
+impl<F: Fn(Arg) -> Value, Arg, Value, Seed> NoiseDomain<Arg> for FnNoise<F, Arg, Seed> {
+	fn noise(&self, arg: Arg) -> Value {
+		(self.f)(arg)
+	}
+}
+
+/// A configuration defined by a closure which takes a seed and returns a closure taking `Arg`s.
+///
+/// ```rust
+/// use noise_fn::{Seedable, NoiseDomain, SeedFnNoise};
+///
+/// let noise = SeedFnNoise::new(|seed: u64| move |x: f64| x * (seed % 10) as f64).seed(12345);
+///
+/// assert_eq!(noise.noise(2.0), 10.0);
+/// ```
+#[derive(Copy, Clone)]
+pub struct SeedFnNoise<Make, Arg, Seed = u64> {
+	make: Make,
+	_phantom: PhantomData<fn(Seed, Arg)>,
+}
+
+noise_ops!([Make, Arg, Seed,] SeedFnNoise<Make, Arg, Seed>);
+
+impl<Make, Arg, Seed> SeedFnNoise<Make, Arg, Seed> {
+	pub fn new<F, Value>(make: Make) -> SeedFnNoise<Make, Arg, Seed> where Make: Fn(Seed) -> F, F: Fn(Arg) -> Value {
+		SeedFnNoise { make, _phantom: PhantomData }
+	}
+}
+
+impl<Make: Fn(Seed) -> F, F, Arg, Seed> Seedable for SeedFnNoise<Make, Arg, Seed> {
+	type Seed = Seed;
+	type Seeded = SeededFnNoise<Make, F, Arg, Seed>;
+	
+	fn seed(self, seed: Seed) -> Self::Seeded {
+		SeededFnNoise { f: (self.make)(seed), _phantom: PhantomData }
+	}
+}
+
+/// A seeded [`SeedFnNoise`].
+#[derive(Copy, Clone)]
+pub struct SeededFnNoise<Make, F, Arg, Seed = u64> {
+	f: F,
+	_phantom: PhantomData<fn(Seed, Arg) -> Make>,
+}
+
+noise_ops!([Make, F, Arg, Seed,] SeededFnNoise<Make, F, Arg, Seed>);
+
+impl<Make, F: Fn(Arg) -> Value, Arg, Value, Seed> Noise for SeededFnNoise<Make, F, Arg, Seed> {
+	type Value = Value;
+	type Unseeded = SeedFnNoise<Make, Arg, Seed>;
+}
+
+impl<Make: Fn(Seed) -> F, F, Arg, Seed> Seeded for SeededFnNoise<Make, F, Arg, Seed> {
+	type Config = SeedFnNoise<Make, Arg, Seed>;
+}
+
+impl<Make, F: Fn(Arg) -> Value, Arg, Value, Seed> NoiseDomain<Arg> for SeededFnNoise<Make, F, Arg, Seed> {
+	fn noise(&self, arg: Arg) -> Value {
+		(self.f)(arg)
+	}
+}
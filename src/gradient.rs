@@ -1,4 +1,4 @@
-use super::{Noise, NoiseDomain};
+use super::{Noise, NoiseDomain, noise::NoiseDerivative, ops::noise_ops};
 
 use sized_matrix::{Vector, Dot};
 
@@ -10,6 +10,8 @@ pub struct Gradient<Value: Copy, const N: usize> where Vector<Value, N>: Dot<Out
 	direction: Vector<Value, N>,
 }
 
+noise_ops!([Value: Copy, const N: usize,] Gradient<Value, N> where [Vector<Value, N>: Dot<Output = Value>]);
+
 impl<Value: Copy, const N: usize> Gradient<Value, N> where Vector<Value, N>: Dot<Output = Value> {
 	pub fn new(direction: Vector<Value, N>) -> Self {
 		Self { direction }
@@ -26,3 +28,11 @@ impl<Value: Copy, const N: usize> NoiseDomain<Vector<Value, N>> for Gradient<Val
 		arg.dot(self.direction)
 	}
 }
+
+impl<Value: Copy, const N: usize> NoiseDerivative<Vector<Value, N>> for Gradient<Value, N> where Vector<Value, N>: Dot<Output = Value> {
+	type Derivative = Vector<Value, N>;
+	
+	fn noise_derivative(&self, arg: Vector<Value, N>) -> (Self::Value, Self::Derivative) {
+		(arg.dot(self.direction), self.direction)
+	}
+}
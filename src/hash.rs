@@ -1,4 +1,4 @@
-use super::{NoiseDomain, helpers::{EmptyConfig, SeedOnlyNoise}};
+use super::{NoiseDomain, helpers::{EmptyConfig, SeedOnlyNoise}, ops::noise_ops};
 
 use core::hash::{Hash, Hasher};
 
@@ -10,6 +10,8 @@ pub struct HashNoise {
 	seed: u64,
 }
 
+noise_ops!(unscaled [] HashNoise);
+
 impl HashNoise {
 	pub fn new() -> EmptyConfig<Self> {
 		EmptyConfig::new()
@@ -27,8 +29,51 @@ impl SeedOnlyNoise for HashNoise {
 
 impl<Arg: Hash> NoiseDomain<Arg> for HashNoise {
 	fn noise(&self, arg: Arg) -> u64 {
-		let mut hasher = WyHash::with_seed(self.seed);
+		let mut hasher = PortableHasher::with_seed(self.seed);
 		arg.hash(&mut hasher);
 		hasher.finish()
 	}
 }
+
+/// A [`WyHash`] hasher which writes integers the same way on every platform.
+///
+/// Integers are written as little-endian bytes and `usize` is widened to 64 bits, which matches the native behaviour of
+/// 64-bit little-endian targets.
+pub(crate) struct PortableHasher(WyHash);
+
+impl PortableHasher {
+	pub(crate) fn with_seed(seed: u64) -> Self {
+		PortableHasher(WyHash::with_seed(seed))
+	}
+}
+
+// The signed variants default to calling these.
+impl Hasher for PortableHasher {
+	fn finish(&self) -> u64 {
+		self.0.finish()
+	}
+	
+	fn write(&mut self, bytes: &[u8]) {
+		self.0.write(bytes);
+	}
+	
+	fn write_u16(&mut self, i: u16) {
+		self.0.write(&i.to_le_bytes());
+	}
+	
+	fn write_u32(&mut self, i: u32) {
+		self.0.write(&i.to_le_bytes());
+	}
+	
+	fn write_u64(&mut self, i: u64) {
+		self.0.write(&i.to_le_bytes());
+	}
+	
+	fn write_u128(&mut self, i: u128) {
+		self.0.write(&i.to_le_bytes());
+	}
+	
+	fn write_usize(&mut self, i: usize) {
+		self.write_u64(i as u64);
+	}
+}
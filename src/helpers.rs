@@ -1,6 +1,6 @@
-use super::{Noise, NoiseDomain, Seedable, noise::Seeded};
+use super::{Noise, NoiseDomain, Seedable, ScaleValue, noise::{Seeded, NoiseDerivative}, math::Float, ops::noise_ops};
 
-use core::marker::PhantomData;
+use core::{marker::PhantomData, ops::Mul};
 
 /// A type alias for the configuration struct of a seeded type.
 pub type Config<Noise> = <Noise as Seeded>::Config;
@@ -19,6 +19,17 @@ pub struct EmptyConfig<Noise: SeedOnlyNoise> {
 	_phantom: PhantomData<fn(Noise::Seed) -> Noise>,
 }
 
+noise_ops!(unscaled [Noise: SeedOnlyNoise,] EmptyConfig<Noise>);
+
+// This knows its value type, so only gets `* f64` when it's floating point.
+impl<Noise: SeedOnlyNoise> Mul<f64> for EmptyConfig<Noise> where Noise::Value: Float {
+	type Output = ScaleValue<Self>;
+	
+	fn mul(self, rhs: f64) -> ScaleValue<Self> {
+		ScaleValue::new(self, rhs)
+	}
+}
+
 impl<Noise: SeedOnlyNoise> EmptyConfig<Noise> {
 	pub fn new() -> Self {
 		Self { _phantom: PhantomData }
@@ -53,6 +64,16 @@ pub struct IgnoreSeed<Seed, Inner> {
 	_phantom: PhantomData<fn(Seed) -> Self>,
 }
 
+noise_ops!(unscaled [Seed, Inner,] IgnoreSeed<Seed, Inner>);
+
+impl<Seed, Inner: Noise> Mul<f64> for IgnoreSeed<Seed, Inner> where Inner::Value: Float {
+	type Output = ScaleValue<Self>;
+	
+	fn mul(self, rhs: f64) -> ScaleValue<Self> {
+		ScaleValue::new(self, rhs)
+	}
+}
+
 impl<Seed, Inner: Noise> Noise for IgnoreSeed<Seed, Inner> {
 	type Value = Inner::Value;
 	type Unseeded = Self;
@@ -82,3 +103,11 @@ impl<Arg, Seed, Inner: NoiseDomain<Arg>> NoiseDomain<Arg> for IgnoreSeed<Seed, I
 		self.inner.noise(arg)
 	}
 }
+
+impl<Arg, Seed, Inner: NoiseDerivative<Arg>> NoiseDerivative<Arg> for IgnoreSeed<Seed, Inner> {
+	type Derivative = Inner::Derivative;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		self.inner.noise_derivative(arg)
+	}
+}
@@ -0,0 +1,65 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}, layers::Layers, math::Float, ops::noise_ops};
+
+use core::ops::Mul;
+
+/// A heterogeneous multifractal noise function, based on Musgrave's algorithm.
+///
+/// Each octave is scaled by the value accumulated so far, so that the roughness of the result varies with its height.
+///
+/// `lacunarity`: A multiplier for the frequency from one octave to the next.
+///
+/// `persistence`: A multiplier for the amplitude from one octave to the next.
+///
+/// `offset`: Added to each octave, raising the overall level and how much later octaves contribute.
+///
+/// A good default is `HeteroMulti::new(_, 2.0, 0.5, 0.7)`.
+///
+/// `T` is the floating point type of the parameters and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct HeteroMulti<Inner, const N: usize, T = f64> {
+	layers: Layers<Inner, N, T>,
+	offset: T,
+}
+
+noise_ops!([Inner, const N: usize, T,] HeteroMulti<Inner, N, T>);
+
+impl<Inner: Noise, const N: usize, T: Float> Noise for HeteroMulti<Inner, N, T> {
+	type Value = T;
+	type Unseeded = HeteroMulti<Inner::Unseeded, N, T>;
+}
+
+impl<Inner: Copy, const N: usize, T: Float> HeteroMulti<Inner, N, T> {
+	pub fn new(inner: Inner, lacunarity: T, persistence: T, offset: T) -> HeteroMulti<Inner, N, T> {
+		HeteroMulti {
+			layers: Layers::new(inner, lacunarity, persistence),
+			offset,
+		}
+	}
+}
+
+impl<Inner: Seedable, const N: usize, T> Seedable for HeteroMulti<Inner, N, T> where Inner::Seed: SplitSeed {
+	type Seed = Inner::Seed;
+	type Seeded = HeteroMulti<Inner::Seeded, N, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		HeteroMulti {
+			layers: self.layers.seed(seed),
+			offset: self.offset,
+		}
+	}
+}
+
+impl<Inner: Seeded, const N: usize, T> Seeded for HeteroMulti<Inner, N, T> where <Inner::Config as Seedable>::Seed: SplitSeed {
+	type Config = HeteroMulti<Inner::Config, N, T>;
+}
+
+impl<Arg: Copy + Mul<T>, Inner: NoiseDomain<Arg::Output, Value = T>, const N: usize, T: Float> NoiseDomain<Arg> for HeteroMulti<Inner, N, T> {
+	fn noise(&self, arg: Arg) -> T {
+		let mut sum = T::zero();
+		for (i, (value, amplitude)) in self.layers.octaves(arg).enumerate() {
+			let signal = (value + self.offset) * amplitude;
+			sum += if i == 0 { signal } else { signal * sum };
+		}
+		sum
+	}
+}
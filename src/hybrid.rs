@@ -0,0 +1,68 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}, layers::Layers, math::Float, ops::noise_ops};
+
+use core::ops::Mul;
+
+/// A hybrid multifractal noise function, based on Musgrave's algorithm.
+///
+/// Each octave is weighted by the running product of the previous octaves, so that low areas stay smooth while high areas get rougher.
+///
+/// `lacunarity`: A multiplier for the frequency from one octave to the next.
+///
+/// `persistence`: A multiplier for the amplitude from one octave to the next.
+///
+/// `offset`: Added to each octave before weighting, controlling how quickly detail builds up.
+///
+/// A good default is `HybridMulti::new(_, 2.0, 0.5, 0.7)`.
+///
+/// `T` is the floating point type of the parameters and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct HybridMulti<Inner, const N: usize, T = f64> {
+	layers: Layers<Inner, N, T>,
+	offset: T,
+}
+
+noise_ops!([Inner, const N: usize, T,] HybridMulti<Inner, N, T>);
+
+impl<Inner: Noise, const N: usize, T: Float> Noise for HybridMulti<Inner, N, T> {
+	type Value = T;
+	type Unseeded = HybridMulti<Inner::Unseeded, N, T>;
+}
+
+impl<Inner: Copy, const N: usize, T: Float> HybridMulti<Inner, N, T> {
+	pub fn new(inner: Inner, lacunarity: T, persistence: T, offset: T) -> HybridMulti<Inner, N, T> {
+		HybridMulti {
+			layers: Layers::new(inner, lacunarity, persistence),
+			offset,
+		}
+	}
+}
+
+impl<Inner: Seedable, const N: usize, T> Seedable for HybridMulti<Inner, N, T> where Inner::Seed: SplitSeed {
+	type Seed = Inner::Seed;
+	type Seeded = HybridMulti<Inner::Seeded, N, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		HybridMulti {
+			layers: self.layers.seed(seed),
+			offset: self.offset,
+		}
+	}
+}
+
+impl<Inner: Seeded, const N: usize, T> Seeded for HybridMulti<Inner, N, T> where <Inner::Config as Seedable>::Seed: SplitSeed {
+	type Config = HybridMulti<Inner::Config, N, T>;
+}
+
+impl<Arg: Copy + Mul<T>, Inner: NoiseDomain<Arg::Output, Value = T>, const N: usize, T: Float> NoiseDomain<Arg> for HybridMulti<Inner, N, T> {
+	fn noise(&self, arg: Arg) -> T {
+		let mut sum = T::zero();
+		let mut weight = T::one();
+		for (value, amplitude) in self.layers.octaves(arg) {
+			let signal = (value + self.offset) * amplitude;
+			sum += weight * signal;
+			weight = weight * signal;
+			weight = if weight > T::one() { T::one() } else { weight };
+		}
+		sum
+	}
+}
@@ -0,0 +1,49 @@
+use super::{NoiseDomain, Seedable, noise::{Seeded, SplitSeed}, math::{Float, powi}};
+
+use core::ops::Mul;
+use higher_order_functions::{Zip, Init};
+
+/// The octaves shared by the multifractal noise functions: `N` copies of an inner noise function, each with its own frequency and amplitude.
+#[derive(Copy, Clone)]
+pub(crate) struct Layers<Inner, const N: usize, T> {
+	inners: [Inner; N],
+	frequencies: [T; N],
+	amplitudes: [T; N],
+}
+
+impl<Inner: Copy, const N: usize, T: Float> Layers<Inner, N, T> {
+	pub(crate) fn new(inner: Inner, lacunarity: T, persistence: T) -> Layers<Inner, N, T> {
+		Layers {
+			inners: [inner; N],
+			frequencies: Init::init(|i| powi(lacunarity, i as i32)),
+			amplitudes: Init::init(|i| powi(persistence, i as i32)),
+		}
+	}
+}
+
+impl<Inner, const N: usize, T: Copy> Layers<Inner, N, T> {
+	/// Evaluate each octave at `arg`, giving its value and amplitude in order.
+	pub(crate) fn octaves<'a, Arg: Copy + Mul<T> + 'a>(&'a self, arg: Arg) -> impl Iterator<Item = (T, T)> + 'a where
+		Inner: NoiseDomain<Arg::Output, Value = T>,
+	{
+		self.inners.iter().zip(self.frequencies).zip(self.amplitudes)
+			.map(move |((inner, frequency), amplitude)| (inner.noise(arg * frequency), amplitude))
+	}
+}
+
+impl<Inner: Seedable, const N: usize, T> Seedable for Layers<Inner, N, T> where Inner::Seed: SplitSeed {
+	type Seed = Inner::Seed;
+	type Seeded = Layers<Inner::Seeded, N, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Layers {
+			inners: self.inners.zip_with(Init::init(|i| i), |c, i| c.seed(seed.split(i))),
+			frequencies: self.frequencies,
+			amplitudes: self.amplitudes,
+		}
+	}
+}
+
+impl<Inner: Seeded, const N: usize, T> Seeded for Layers<Inner, N, T> where <Inner::Config as Seedable>::Seed: SplitSeed {
+	type Config = Layers<Inner::Config, N, T>;
+}
@@ -0,0 +1,86 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
+
+#[cfg(feature = "simd")]
+use higher_order_functions::Zip;
+
+use core::ops::{Add, Sub, Mul};
+
+/// A noise function which blends between two other noise functions, using a third to control the blend.
+///
+/// `noise(x) = inner1.noise(x) + (inner2.noise(x) - inner1.noise(x)) * control.noise(x)`
+///
+/// A control value of 0 gives `inner1` and 1 gives `inner2`, so a control noise function in [-1, 1] will usually need to be
+/// scaled, e.g. with `AddNoise` and `ScaleNoise`.
+///
+/// The control values only need to multiply the blended values, so e.g. vector valued noise can be blended using scalar noise.
+#[derive(Copy, Clone)]
+pub struct LerpNoise<Inner1, Inner2, Control> {
+	inner1: Inner1,
+	inner2: Inner2,
+	control: Control,
+}
+
+noise_ops!([Inner1, Inner2, Control,] LerpNoise<Inner1, Inner2, Control>);
+
+impl<Value, Inner1, Inner2, Control: Noise> Noise for LerpNoise<Inner1, Inner2, Control> where
+	Value: Copy + Add<Output = Value> + Sub<Output = Value> + Mul<Control::Value, Output = Value>,
+	Inner1: Noise<Value = Value>,
+	Inner2: Noise<Value = Value>,
+{
+	type Value = Value;
+	type Unseeded = LerpNoise<Inner1::Unseeded, Inner2::Unseeded, Control::Unseeded>;
+}
+
+impl<Inner1, Inner2, Control> LerpNoise<Inner1, Inner2, Control> {
+	pub fn new(inner1: Inner1, inner2: Inner2, control: Control) -> LerpNoise<Inner1, Inner2, Control> {
+		LerpNoise { inner1, inner2, control }
+	}
+}
+
+impl<Seed: SplitSeed, Inner1: Seedable<Seed = Seed>, Inner2: Seedable<Seed = Seed>, Control: Seedable<Seed = Seed>> Seedable for LerpNoise<Inner1, Inner2, Control> {
+	type Seed = Seed;
+	type Seeded = LerpNoise<Inner1::Seeded, Inner2::Seeded, Control::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		LerpNoise {
+			inner1: self.inner1.seed(seed.split(0)),
+			inner2: self.inner2.seed(seed.split(1)),
+			control: self.control.seed(seed.split(2)),
+		}
+	}
+}
+
+impl<Seed: SplitSeed, Inner1: Seeded, Inner2: Seeded, Control: Seeded> Seeded for LerpNoise<Inner1, Inner2, Control> where
+	Inner1::Config: Seedable<Seed = Seed>,
+	Inner2::Config: Seedable<Seed = Seed>,
+	Control::Config: Seedable<Seed = Seed>,
+{
+	type Config = LerpNoise<Inner1::Config, Inner2::Config, Control::Config>;
+}
+
+impl<Arg: Copy, Value, Inner1, Inner2, Control: NoiseDomain<Arg>> NoiseDomain<Arg> for LerpNoise<Inner1, Inner2, Control> where
+	Value: Copy + Add<Output = Value> + Sub<Output = Value> + Mul<Control::Value, Output = Value>,
+	Inner1: NoiseDomain<Arg, Value = Value>,
+	Inner2: NoiseDomain<Arg, Value = Value>,
+{
+	fn noise(&self, arg: Arg) -> Value {
+		let value1 = self.inner1.noise(arg);
+		value1 + (self.inner2.noise(arg) - value1) * self.control.noise(arg)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg: Copy, Value, Inner1, Inner2, Control: NoiseBatch<Arg>> NoiseBatch<Arg> for LerpNoise<Inner1, Inner2, Control> where
+	Value: Copy + Add<Output = Value> + Sub<Output = Value> + Mul<Control::Value, Output = Value>,
+	Inner1: NoiseBatch<Arg, Value = Value>,
+	Inner2: NoiseBatch<Arg, Value = Value>,
+{
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Value; L] {
+		self.inner1.noise_batch(args)
+			.zip_with(self.inner2.noise_batch(args), |value1, value2| (value1, value2))
+			.zip_with(self.control.noise_batch(args), |(value1, value2), control| value1 + (value2 - value1) * control)
+	}
+}
@@ -21,42 +21,136 @@
 #![no_std]
 
 #![feature(associated_type_defaults)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
 #![doc(html_root_url = "https://docs.rs/noise_fn/0.2.0")]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("noise_fn requires either the `std` or `libm` feature to be enabled");
+
+// x87 floating point uses extended precision for intermediate results.
+#[cfg(all(feature = "deterministic", target_arch = "x86", not(target_feature = "sse2")))]
+compile_error!("the `deterministic` feature requires SSE2 on x86 targets");
+
 pub mod noise;
 pub mod helpers;
 pub mod perm_table;
+mod math;
+mod fixed;
 mod white;
 mod hash;
 mod simplex;
+mod fixed_simplex;
+mod perlin;
+mod worley;
+mod value;
+mod fixed_value;
+mod open_simplex;
 mod scale;
+mod scale_value;
 mod add;
+mod sub;
+mod mul;
+mod neg;
+mod min;
+mod max;
+mod lerp;
+mod map;
+mod map_arg;
+mod fn_noise;
+mod clamp;
+mod abs;
+mod pow;
+mod terrace;
+mod curve;
 mod constant;
 mod gradient;
 mod sum;
 mod octaves;
+mod layers;
+mod billow;
+mod ridged;
+mod hybrid;
+mod hetero;
+mod random_transform;
+#[cfg(feature = "alloc")]
+mod dyn_sum;
+#[cfg(feature = "alloc")]
+mod dyn_octaves;
 mod to_float;
+mod curl;
+mod warp;
+mod periodic;
+mod ext;
+mod ops;
 
 pub use {
 	noise::{
 		Noise,
 		Seedable,
 		NoiseDomain,
+		NoiseDerivative,
+		GridArg,
 	},
 	helpers::Config,
+	math::Float,
+	ext::NoiseExt,
+	ops::NoiseOperand,
 	white::WhiteNoise,
 	hash::HashNoise,
 	simplex::Simplex,
+	perlin::Perlin,
+	worley::{Worley, WorleyDistance, WorleyReturn},
+	value::{ValueNoise, Interpolation},
+	fixed_value::FixedValueNoise,
+	open_simplex::{OpenSimplex2, OpenSimplex2S, OpenSimplexOrientation},
 	scale::ScaleNoise,
+	scale_value::ScaleValue,
 	add::AddNoise,
+	sub::SubNoise,
+	mul::MulNoise,
+	neg::NegNoise,
+	min::MinNoise,
+	max::MaxNoise,
+	lerp::LerpNoise,
+	map::MapNoise,
+	map_arg::MapArg,
+	fn_noise::{FnNoise, SeedFnNoise, SeededFnNoise},
+	clamp::Clamp,
+	abs::Abs,
+	pow::Pow,
+	terrace::Terrace,
+	curve::Curve,
 	constant::Constant,
 	gradient::Gradient,
 	sum::SumNoise,
 	octaves::Octaves,
+	billow::Billow,
+	ridged::RidgedMulti,
+	hybrid::HybridMulti,
+	hetero::HeteroMulti,
+	random_transform::RandomTransform,
 	to_float::ToFloat,
+	curl::CurlNoise,
+	warp::Warp,
+	periodic::Periodic,
 };
 
+#[cfg(feature = "alloc")]
+pub use {
+	dyn_sum::DynSumNoise,
+	dyn_octaves::DynOctaves,
+};
+
+#[cfg(feature = "simd")]
+pub use noise::NoiseBatch;
+
 // Include the readme and changelog as hidden documentation so they're tested by cargo test
 #[doc = include_str!("../README.md")]
 #[doc = include_str!("../CHANGELOG.md")]
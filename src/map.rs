@@ -0,0 +1,46 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, ops::noise_ops};
+
+/// A noise function which transforms the values of another noise function using a closure.
+///
+/// `noise(x) = f(inner.noise(x))`
+#[derive(Copy, Clone)]
+pub struct MapNoise<Inner, F> {
+	inner: Inner,
+	f: F,
+}
+
+noise_ops!([Inner, F,] MapNoise<Inner, F>);
+
+impl<Inner: Noise, F: Fn(Inner::Value) -> Value, Value> Noise for MapNoise<Inner, F> {
+	type Value = Value;
+	type Unseeded = MapNoise<Inner::Unseeded, F>;
+}
+
+impl<Inner, F> MapNoise<Inner, F> {
+	pub fn new(inner: Inner, f: F) -> MapNoise<Inner, F> {
+		MapNoise { inner, f }
+	}
+}
+
+impl<Inner: Seedable, F> Seedable for MapNoise<Inner, F> {
+	type Seed = Inner::Seed;
+	type Seeded = MapNoise<Inner::Seeded, F>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		MapNoise { inner: self.inner.seed(seed), f: self.f }
+	}
+}
+
+impl<Inner: Seeded, F> Seeded for MapNoise<Inner, F> {
+	type Config = MapNoise<Inner::Config, F>;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg>, F: Fn(Inner::Value) -> Value, Value> NoiseDomain<Arg> for MapNoise<Inner, F> {
+	fn noise(&self, arg: Arg) -> Value {
+		(self.f)(self.inner.noise(arg))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Value> {
+		self.inner.noise_points(points).map(|value| (self.f)(value))
+	}
+}
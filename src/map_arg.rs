@@ -0,0 +1,46 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, ops::noise_ops};
+
+/// A noise function which transforms its input using a closure before passing it to another noise function.
+///
+/// `noise(x) = inner.noise(f(x))`
+#[derive(Copy, Clone)]
+pub struct MapArg<Inner, F> {
+	inner: Inner,
+	f: F,
+}
+
+noise_ops!([Inner, F,] MapArg<Inner, F>);
+
+impl<Inner: Noise, F> Noise for MapArg<Inner, F> {
+	type Value = Inner::Value;
+	type Unseeded = MapArg<Inner::Unseeded, F>;
+}
+
+impl<Inner, F> MapArg<Inner, F> {
+	pub fn new(inner: Inner, f: F) -> MapArg<Inner, F> {
+		MapArg { inner, f }
+	}
+}
+
+impl<Inner: Seedable, F> Seedable for MapArg<Inner, F> {
+	type Seed = Inner::Seed;
+	type Seeded = MapArg<Inner::Seeded, F>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		MapArg { inner: self.inner.seed(seed), f: self.f }
+	}
+}
+
+impl<Inner: Seeded, F> Seeded for MapArg<Inner, F> {
+	type Config = MapArg<Inner::Config, F>;
+}
+
+impl<Arg, InnerArg, Inner: NoiseDomain<InnerArg>, F: Fn(Arg) -> InnerArg> NoiseDomain<Arg> for MapArg<Inner, F> {
+	fn noise(&self, arg: Arg) -> Self::Value {
+		self.inner.noise((self.f)(arg))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points.map(|arg| (self.f)(arg)))
+	}
+}
@@ -0,0 +1,399 @@
+//! Floating point functions which aren't available in `core`.
+//!
+//! These use `libm` when the `libm` feature is enabled, and `std` otherwise.
+//!
+//! With the `deterministic` feature, products are also summed with separate multiplies and adds in a fixed order,
+//! rather than with fused multiply-add, so results don't depend on the target's floating point support.
+
+use sized_matrix::{Vector, Matrix};
+
+#[cfg(not(feature = "deterministic"))]
+use sized_matrix::Dot;
+
+#[cfg(feature = "deterministic")]
+use higher_order_functions::Init;
+
+use super::to_float::{bits_to_f64, bits_to_f32};
+
+#[cfg(feature = "simd")]
+use std::simd::{Simd, StdFloat, Select, cmp::SimdPartialOrd};
+
+use core::ops::{Add, Sub, Mul, Div, Neg, AddAssign};
+use num_traits::{Zero, One, MulAdd, Unsigned, PrimInt, AsPrimitive};
+
+/// A floating point type which noise functions can be evaluated in, i.e. `f32` or `f64`.
+pub trait Float: Copy + PartialOrd + Zero + One + MulAdd<Output = Self> + AddAssign +
+	Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+	fn floor(self) -> Self;
+	fn round(self) -> Self;
+	fn sqrt(self) -> Self;
+	fn powi(self, n: i32) -> Self;
+	fn powf(self, n: Self) -> Self;
+	fn abs(self) -> Self;
+	fn sin_cos(self) -> (Self, Self);
+	
+	/// Convert from an `f64`, rounding to the nearest value.
+	fn from_f64(x: f64) -> Self;
+	
+	/// Convert from an `i64`, rounding to the nearest value.
+	fn from_i64(x: i64) -> Self;
+	
+	/// Convert to an `i64`, rounding towards zero.
+	fn to_i64(self) -> i64;
+	
+	/// Convert random uint values to numbers in the range [0, 1), as in [`ToFloat`](super::ToFloat).
+	fn from_random_bits<T: Unsigned + PrimInt + AsPrimitive<u64>>(value: T) -> Self;
+}
+
+impl Float for f64 {
+	#[cfg(feature = "libm")]
+	fn floor(self) -> f64 {
+		libm::floor(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn floor(self) -> f64 {
+		f64::floor(self)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn round(self) -> f64 {
+		libm::round(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn round(self) -> f64 {
+		f64::round(self)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn sqrt(self) -> f64 {
+		libm::sqrt(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn sqrt(self) -> f64 {
+		f64::sqrt(self)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn powi(self, n: i32) -> f64 {
+		powi_by_squaring(self, n)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn powi(self, n: i32) -> f64 {
+		f64::powi(self, n)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn powf(self, n: f64) -> f64 {
+		libm::pow(self, n)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn powf(self, n: f64) -> f64 {
+		f64::powf(self, n)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn abs(self) -> f64 {
+		libm::fabs(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn abs(self) -> f64 {
+		f64::abs(self)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn sin_cos(self) -> (f64, f64) {
+		libm::sincos(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn sin_cos(self) -> (f64, f64) {
+		f64::sin_cos(self)
+	}
+	
+	fn from_f64(x: f64) -> f64 {
+		x
+	}
+	
+	fn from_i64(x: i64) -> f64 {
+		x as f64
+	}
+	
+	fn to_i64(self) -> i64 {
+		self as i64
+	}
+	
+	fn from_random_bits<T: Unsigned + PrimInt + AsPrimitive<u64>>(value: T) -> f64 {
+		bits_to_f64(value)
+	}
+}
+
+impl Float for f32 {
+	#[cfg(feature = "libm")]
+	fn floor(self) -> f32 {
+		libm::floorf(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn floor(self) -> f32 {
+		f32::floor(self)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn round(self) -> f32 {
+		libm::roundf(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn round(self) -> f32 {
+		f32::round(self)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn sqrt(self) -> f32 {
+		libm::sqrtf(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn sqrt(self) -> f32 {
+		f32::sqrt(self)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn powi(self, n: i32) -> f32 {
+		powi_by_squaring(self, n)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn powi(self, n: i32) -> f32 {
+		f32::powi(self, n)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn powf(self, n: f32) -> f32 {
+		libm::powf(self, n)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn powf(self, n: f32) -> f32 {
+		f32::powf(self, n)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn abs(self) -> f32 {
+		libm::fabsf(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn abs(self) -> f32 {
+		f32::abs(self)
+	}
+	
+	#[cfg(feature = "libm")]
+	fn sin_cos(self) -> (f32, f32) {
+		libm::sincosf(self)
+	}
+	
+	#[cfg(not(feature = "libm"))]
+	fn sin_cos(self) -> (f32, f32) {
+		f32::sin_cos(self)
+	}
+	
+	fn from_f64(x: f64) -> f32 {
+		x as f32
+	}
+	
+	fn from_i64(x: i64) -> f32 {
+		x as f32
+	}
+	
+	fn to_i64(self) -> i64 {
+		self as i64
+	}
+	
+	fn from_random_bits<T: Unsigned + PrimInt + AsPrimitive<u64>>(value: T) -> f32 {
+		bits_to_f32(value)
+	}
+}
+
+/// Lane-wise operations on SIMD vectors of a [`Float`] type.
+#[cfg(feature = "simd")]
+pub(crate) trait SimdFloat: Float {
+	type Lanes<const L: usize>: Copy + Add<Output = Self::Lanes<L>> + Sub<Output = Self::Lanes<L>> + Mul<Output = Self::Lanes<L>>;
+	
+	fn splat<const L: usize>(x: Self) -> Self::Lanes<L>;
+	fn from_array<const L: usize>(x: [Self; L]) -> Self::Lanes<L>;
+	fn to_array<const L: usize>(x: Self::Lanes<L>) -> [Self; L];
+	fn floor_lanes<const L: usize>(x: Self::Lanes<L>) -> Self::Lanes<L>;
+	#[cfg(not(feature = "deterministic"))]
+	fn mul_add_lanes<const L: usize>(a: Self::Lanes<L>, b: Self::Lanes<L>, c: Self::Lanes<L>) -> Self::Lanes<L>;
+	
+	/// Zero in the lanes where `t` is negative, and `x` in the others.
+	fn zero_where_negative<const L: usize>(t: Self::Lanes<L>, x: Self::Lanes<L>) -> Self::Lanes<L>;
+	
+	/// One in the lanes where `a > b` (or `a >= b` if `or_equal`), and zero in the others.
+	fn one_where_greater<const L: usize>(a: Self::Lanes<L>, b: Self::Lanes<L>, or_equal: bool) -> Self::Lanes<L>;
+}
+
+#[cfg(feature = "simd")]
+impl SimdFloat for f64 {
+	type Lanes<const L: usize> = Simd<f64, L>;
+	
+	fn splat<const L: usize>(x: f64) -> Simd<f64, L> {
+		Simd::splat(x)
+	}
+	
+	fn from_array<const L: usize>(x: [f64; L]) -> Simd<f64, L> {
+		Simd::from_array(x)
+	}
+	
+	fn to_array<const L: usize>(x: Simd<f64, L>) -> [f64; L] {
+		x.to_array()
+	}
+	
+	fn floor_lanes<const L: usize>(x: Simd<f64, L>) -> Simd<f64, L> {
+		x.floor()
+	}
+	
+	#[cfg(not(feature = "deterministic"))]
+	fn mul_add_lanes<const L: usize>(a: Simd<f64, L>, b: Simd<f64, L>, c: Simd<f64, L>) -> Simd<f64, L> {
+		a.mul_add(b, c)
+	}
+	
+	fn zero_where_negative<const L: usize>(t: Simd<f64, L>, x: Simd<f64, L>) -> Simd<f64, L> {
+		let zero = Simd::splat(0.);
+		t.simd_lt(zero).select(zero, x)
+	}
+	
+	fn one_where_greater<const L: usize>(a: Simd<f64, L>, b: Simd<f64, L>, or_equal: bool) -> Simd<f64, L> {
+		let mask = if or_equal { a.simd_ge(b) } else { a.simd_gt(b) };
+		mask.select(Simd::splat(1.), Simd::splat(0.))
+	}
+}
+
+#[cfg(feature = "simd")]
+impl SimdFloat for f32 {
+	type Lanes<const L: usize> = Simd<f32, L>;
+	
+	fn splat<const L: usize>(x: f32) -> Simd<f32, L> {
+		Simd::splat(x)
+	}
+	
+	fn from_array<const L: usize>(x: [f32; L]) -> Simd<f32, L> {
+		Simd::from_array(x)
+	}
+	
+	fn to_array<const L: usize>(x: Simd<f32, L>) -> [f32; L] {
+		x.to_array()
+	}
+	
+	fn floor_lanes<const L: usize>(x: Simd<f32, L>) -> Simd<f32, L> {
+		x.floor()
+	}
+	
+	#[cfg(not(feature = "deterministic"))]
+	fn mul_add_lanes<const L: usize>(a: Simd<f32, L>, b: Simd<f32, L>, c: Simd<f32, L>) -> Simd<f32, L> {
+		a.mul_add(b, c)
+	}
+	
+	fn zero_where_negative<const L: usize>(t: Simd<f32, L>, x: Simd<f32, L>) -> Simd<f32, L> {
+		let zero = Simd::splat(0.);
+		t.simd_lt(zero).select(zero, x)
+	}
+	
+	fn one_where_greater<const L: usize>(a: Simd<f32, L>, b: Simd<f32, L>, or_equal: bool) -> Simd<f32, L> {
+		let mask = if or_equal { a.simd_ge(b) } else { a.simd_gt(b) };
+		mask.select(Simd::splat(1.), Simd::splat(0.))
+	}
+}
+
+// libm has no powi, so use exponentiation by squaring.
+#[cfg(feature = "libm")]
+fn powi_by_squaring<T: Float>(x: T, n: i32) -> T {
+	let mut base = x;
+	let mut exponent = n.unsigned_abs();
+	let mut result = T::one();
+	while exponent > 0 {
+		if exponent & 1 == 1 {
+			result = result * base;
+		}
+		base = base * base;
+		exponent >>= 1;
+	}
+	if n < 0 { T::one() / result } else { result }
+}
+
+pub fn floor<T: Float>(x: T) -> T {
+	x.floor()
+}
+
+pub fn round<T: Float>(x: T) -> T {
+	x.round()
+}
+
+pub fn sqrt<T: Float>(x: T) -> T {
+	x.sqrt()
+}
+
+pub fn powi<T: Float>(x: T, n: i32) -> T {
+	x.powi(n)
+}
+
+pub fn sin_cos<T: Float>(x: T) -> (T, T) {
+	x.sin_cos()
+}
+
+#[cfg(feature = "deterministic")]
+pub fn dot<T: Float, const N: usize>(a: Vector<T, N>, b: Vector<T, N>) -> T {
+	let mut result = T::zero();
+	for i in 0..N {
+		result += a[i] * b[i];
+	}
+	result
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn dot<T: Float, const N: usize>(a: Vector<T, N>, b: Vector<T, N>) -> T {
+	a.dot(b)
+}
+
+#[cfg(all(feature = "simd", feature = "deterministic"))]
+pub(crate) fn dot_lanes<T: SimdFloat, const N: usize, const L: usize>(a: [T::Lanes<L>; N], b: [T::Lanes<L>; N]) -> T::Lanes<L> {
+	let mut result = T::splat(T::zero());
+	for i in 0..N {
+		result = result + a[i] * b[i];
+	}
+	result
+}
+
+// Fused in the same order as `Dot` on `Vector`, so the lanes match `dot` exactly.
+#[cfg(all(feature = "simd", not(feature = "deterministic")))]
+pub(crate) fn dot_lanes<T: SimdFloat, const N: usize, const L: usize>(a: [T::Lanes<L>; N], b: [T::Lanes<L>; N]) -> T::Lanes<L> {
+	let mut result = T::splat(T::zero());
+	for i in 0..N {
+		result = T::mul_add_lanes(a[i], b[i], result);
+	}
+	result
+}
+
+#[cfg(feature = "deterministic")]
+pub fn mul<T: Float, const M: usize, const K: usize, const N: usize>(a: Matrix<T, M, K>, b: Matrix<T, K, N>) -> Matrix<T, M, N> {
+	Matrix::init(|[row, col]: [usize; 2]| {
+		let mut result = T::zero();
+		for k in 0..K {
+			result += a[[row, k]] * b[[k, col]];
+		}
+		result
+	})
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn mul<T: Float, const M: usize, const K: usize, const N: usize>(a: Matrix<T, M, K>, b: Matrix<T, K, N>) -> Matrix<T, M, N> {
+	a * b
+}
@@ -0,0 +1,76 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
+
+#[cfg(feature = "simd")]
+use higher_order_functions::Zip;
+
+/// A noise function which takes the smaller value of two other noise functions.
+///
+/// `noise(x) = min(inner1.noise(x), inner2.noise(x))`
+#[derive(Copy, Clone)]
+pub struct MinNoise<Inner1, Inner2> {
+	inner1: Inner1,
+	inner2: Inner2,
+}
+
+noise_ops!([Inner1, Inner2,] MinNoise<Inner1, Inner2>);
+
+impl<Inner1: Noise, Inner2: Noise<Value = Inner1::Value>> Noise for MinNoise<Inner1, Inner2> where Inner1::Value: PartialOrd {
+	type Value = Inner1::Value;
+	type Unseeded = MinNoise<Inner1::Unseeded, Inner2::Unseeded>;
+}
+
+impl<Inner1, Inner2> MinNoise<Inner1, Inner2> {
+	pub fn new(inner1: Inner1, inner2: Inner2) -> MinNoise<Inner1, Inner2> {
+		MinNoise { inner1, inner2 }
+	}
+}
+
+impl<Seed: SplitSeed, Inner1: Seedable<Seed = Seed>, Inner2: Seedable<Seed = Seed>> Seedable for MinNoise<Inner1, Inner2> {
+	type Seed = Seed;
+	type Seeded = MinNoise<Inner1::Seeded, Inner2::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		MinNoise { inner1: self.inner1.seed(seed.split(0)), inner2: self.inner2.seed(seed.split(1)) }
+	}
+}
+
+impl<Seed: SplitSeed, Inner1: Seeded, Inner2: Seeded> Seeded for MinNoise<Inner1, Inner2> where
+	Inner1::Config: Seedable<Seed = Seed>,
+	Inner2::Config: Seedable<Seed = Seed>,
+{
+	type Config = MinNoise<Inner1::Config, Inner2::Config>;
+}
+
+impl<Arg: Copy, Inner1: NoiseDomain<Arg>, Inner2: NoiseDomain<Arg, Value = Inner1::Value>> NoiseDomain<Arg> for MinNoise<Inner1, Inner2> where
+	Inner1::Value: PartialOrd,
+{
+	fn noise(&self, arg: Arg) -> Self::Value {
+		let value1 = self.inner1.noise(arg);
+		let value2 = self.inner2.noise(arg);
+		if value2 < value1 { value2 } else { value1 }
+	}
+}
+
+impl<Arg: Copy, Inner1: NoiseDerivative<Arg>, Inner2: NoiseDerivative<Arg, Value = Inner1::Value, Derivative = Inner1::Derivative>> NoiseDerivative<Arg> for MinNoise<Inner1, Inner2> where
+	Inner1::Value: PartialOrd,
+{
+	type Derivative = Inner1::Derivative;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let value1 = self.inner1.noise_derivative(arg);
+		let value2 = self.inner2.noise_derivative(arg);
+		if value2.0 < value1.0 { value2 } else { value1 }
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg: Copy, Inner1: NoiseBatch<Arg>, Inner2: NoiseBatch<Arg, Value = Inner1::Value>> NoiseBatch<Arg> for MinNoise<Inner1, Inner2> where
+	Inner1::Value: PartialOrd,
+{
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		self.inner1.noise_batch(args).zip_with(self.inner2.noise_batch(args), |value1, value2| if value2 < value1 { value2 } else { value1 })
+	}
+}
@@ -0,0 +1,80 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
+
+#[cfg(feature = "simd")]
+use higher_order_functions::Zip;
+
+use core::ops::{Add, Mul};
+
+/// A noise function which multiplies two other noise functions.
+///
+/// `noise(x) = inner1.noise(x) * inner2.noise(x)`
+#[derive(Copy, Clone)]
+pub struct MulNoise<Inner1, Inner2> {
+	inner1: Inner1,
+	inner2: Inner2,
+}
+
+noise_ops!([Inner1, Inner2,] MulNoise<Inner1, Inner2>);
+
+impl<Inner1: Noise, Inner2: Noise> Noise for MulNoise<Inner1, Inner2> where Inner1::Value: Mul<Inner2::Value> {
+	type Value = <Inner1::Value as Mul<Inner2::Value>>::Output;
+	type Unseeded = MulNoise<Inner1::Unseeded, Inner2::Unseeded>;
+}
+
+impl<Inner1, Inner2> MulNoise<Inner1, Inner2> {
+	pub fn new(inner1: Inner1, inner2: Inner2) -> MulNoise<Inner1, Inner2> {
+		MulNoise { inner1, inner2 }
+	}
+}
+
+impl<Seed: SplitSeed, Inner1: Seedable<Seed = Seed>, Inner2: Seedable<Seed = Seed>> Seedable for MulNoise<Inner1, Inner2> {
+	type Seed = Seed;
+	type Seeded = MulNoise<Inner1::Seeded, Inner2::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		MulNoise { inner1: self.inner1.seed(seed.split(0)), inner2: self.inner2.seed(seed.split(1)) }
+	}
+}
+
+impl<Seed: SplitSeed, Inner1: Seeded, Inner2: Seeded> Seeded for MulNoise<Inner1, Inner2> where
+	Inner1::Config: Seedable<Seed = Seed>,
+	Inner2::Config: Seedable<Seed = Seed>,
+{
+	type Config = MulNoise<Inner1::Config, Inner2::Config>;
+}
+
+impl<Arg: Copy, Inner1: NoiseDomain<Arg>, Inner2: NoiseDomain<Arg>> NoiseDomain<Arg> for MulNoise<Inner1, Inner2> where
+	Inner1::Value: Mul<Inner2::Value>,
+{
+	fn noise(&self, arg: Arg) -> Self::Value {
+		self.inner1.noise(arg) * self.inner2.noise(arg)
+	}
+}
+
+impl<Arg: Copy, Inner1: NoiseDerivative<Arg>, Inner2: NoiseDerivative<Arg>> NoiseDerivative<Arg> for MulNoise<Inner1, Inner2> where
+	Inner1::Value: Mul<Inner2::Value> + Copy,
+	Inner2::Value: Copy,
+	Inner1::Derivative: Mul<Inner2::Value>,
+	Inner2::Derivative: Mul<Inner1::Value>,
+	<Inner1::Derivative as Mul<Inner2::Value>>::Output: Add<<Inner2::Derivative as Mul<Inner1::Value>>::Output>,
+{
+	type Derivative = <<Inner1::Derivative as Mul<Inner2::Value>>::Output as Add<<Inner2::Derivative as Mul<Inner1::Value>>::Output>>::Output;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let (value1, derivative1) = self.inner1.noise_derivative(arg);
+		let (value2, derivative2) = self.inner2.noise_derivative(arg);
+		(value1 * value2, derivative1 * value2 + derivative2 * value1)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg: Copy, Inner1: NoiseBatch<Arg>, Inner2: NoiseBatch<Arg>> NoiseBatch<Arg> for MulNoise<Inner1, Inner2> where
+	Inner1::Value: Mul<Inner2::Value>,
+{
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		self.inner1.noise_batch(args).zip_with(self.inner2.noise_batch(args), |value1, value2| value1 * value2)
+	}
+}
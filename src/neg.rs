@@ -0,0 +1,69 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, NoiseDerivative}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
+
+use core::ops::Neg;
+
+/// A noise function which negates another noise function.
+///
+/// `noise(x) = -inner.noise(x)`
+#[derive(Copy, Clone)]
+pub struct NegNoise<Inner> {
+	inner: Inner,
+}
+
+noise_ops!([Inner,] NegNoise<Inner>);
+
+impl<Inner: Noise> Noise for NegNoise<Inner> where Inner::Value: Neg {
+	type Value = <Inner::Value as Neg>::Output;
+	type Unseeded = NegNoise<Inner::Unseeded>;
+}
+
+impl<Inner> NegNoise<Inner> {
+	pub fn new(inner: Inner) -> NegNoise<Inner> {
+		NegNoise { inner }
+	}
+}
+
+impl<Inner: Seedable> Seedable for NegNoise<Inner> {
+	type Seed = Inner::Seed;
+	type Seeded = NegNoise<Inner::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		NegNoise { inner: self.inner.seed(seed) }
+	}
+}
+
+impl<Inner: Seeded> Seeded for NegNoise<Inner> {
+	type Config = NegNoise<Inner::Config>;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg>> NoiseDomain<Arg> for NegNoise<Inner> where Inner::Value: Neg {
+	fn noise(&self, arg: Arg) -> Self::Value {
+		-self.inner.noise(arg)
+	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points).map(|value| -value)
+	}
+}
+
+impl<Arg, Inner: NoiseDerivative<Arg>> NoiseDerivative<Arg> for NegNoise<Inner> where
+	Inner::Value: Neg,
+	Inner::Derivative: Neg,
+{
+	type Derivative = <Inner::Derivative as Neg>::Output;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let (value, derivative) = self.inner.noise_derivative(arg);
+		(-value, -derivative)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg, Inner: NoiseBatch<Arg>> NoiseBatch<Arg> for NegNoise<Inner> where Inner::Value: Neg {
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		self.inner.noise_batch(args).map(|value| -value)
+	}
+}
@@ -1,6 +1,11 @@
 use core::hash::{Hash, Hasher};
 
-use wyhash::WyHash;
+use super::{hash::PortableHasher, math::Float};
+
+use sized_matrix::Vector;
+
+#[cfg(feature = "rayon")]
+use rayon::{slice::ParallelSliceMut, iter::{ParallelIterator, IndexedParallelIterator}};
 
 /// A noise function producing `Value`s.
 pub trait Noise: Sized {
@@ -32,6 +37,113 @@ pub trait Seeded: Sized {
 pub trait NoiseDomain<Arg>: Noise {
 	/// Get the value of the noise at a particular 'location'.
 	fn noise(&self, arg: Arg) -> Self::Value;
+	
+	/// Fill `out` with the values of the noise over a regular grid of `size` points, starting at `origin` and spaced by `step`.
+	///
+	/// Values are stored with the first axis varying fastest, and are exactly the same as calling `noise` at each point.
+	///
+	/// Panics if the length of `out` isn't the number of points in the grid.
+	fn fill_grid(&self, origin: Arg, step: Arg, size: Arg::Size, out: &mut [Self::Value]) where Arg: GridArg {
+		assert_eq!(out.len(), Arg::grid_len(size), "output buffer doesn't match the grid size");
+		self.fill_grid_part(origin, step, size, 0, out);
+	}
+	
+	/// Like `fill_grid`, but only fill the points of the grid from index `start` onwards, e.g. to split a grid between threads.
+	///
+	/// Panics if `out` extends past the end of the grid.
+	fn fill_grid_part(&self, origin: Arg, step: Arg, size: Arg::Size, start: usize, out: &mut [Self::Value]) where Arg: GridArg {
+		assert!(start.checked_add(out.len()).is_some_and(|end| end <= Arg::grid_len(size)), "output buffer extends past the end of the grid");
+		let points = (start..).map(move |index| Arg::grid_point(origin, step, size, index));
+		for (value, noise) in out.iter_mut().zip(self.noise_points(points)) {
+			*value = noise;
+		}
+	}
+	
+	/// Get the values of the noise at each of a sequence of nearby 'locations', exactly the same as calling `noise` at each.
+	///
+	/// Noise functions which can share work between neighbouring points, such as [`Simplex`](crate::Simplex), override this to
+	/// speed up `fill_grid`, and combinators forward it to their inner noise functions so that they get the same speed up.
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		points.map(move |arg| self.noise(arg))
+	}
+	
+	/// Like `fill_grid`, but split between threads using `rayon`.
+	///
+	/// The values are exactly the same as with `fill_grid`, so grids filled either way line up with each other.
+	#[cfg(feature = "rayon")]
+	fn par_fill_grid(&self, origin: Arg, step: Arg, size: Arg::Size, out: &mut [Self::Value]) where
+		Self: Sync,
+		Self::Value: Send,
+		Arg: GridArg + Sync,
+		Arg::Size: Sync,
+	{
+		assert_eq!(out.len(), Arg::grid_len(size), "output buffer doesn't match the grid size");
+		out.par_chunks_mut(PAR_GRID_CHUNK).enumerate().for_each(|(chunk, out)| {
+			self.fill_grid_part(origin, step, size, chunk * PAR_GRID_CHUNK, out);
+		});
+	}
+}
+
+// The number of points filled by each `rayon` task.
+#[cfg(feature = "rayon")]
+const PAR_GRID_CHUNK: usize = 1024;
+
+/// An argument type which can be laid out in a regular grid, for [`NoiseDomain::fill_grid`].
+pub trait GridArg: Copy {
+	/// The number of points along each axis.
+	type Size: Copy;
+	
+	/// The total number of points in a grid of this size.
+	fn grid_len(size: Self::Size) -> usize;
+	
+	/// The `index`th point of the grid, with the first axis varying fastest.
+	fn grid_point(origin: Self, step: Self, size: Self::Size, index: usize) -> Self;
+}
+
+impl<T: Float> GridArg for T {
+	type Size = usize;
+	
+	fn grid_len(size: usize) -> usize {
+		size
+	}
+	
+	fn grid_point(origin: T, step: T, _size: usize, index: usize) -> T {
+		origin + step * T::from_i64(index as i64)
+	}
+}
+
+impl<T: Float, const N: usize> GridArg for Vector<T, N> {
+	type Size = [usize; N];
+	
+	fn grid_len(size: [usize; N]) -> usize {
+		size.iter().product()
+	}
+	
+	fn grid_point(origin: Self, step: Self, size: [usize; N], index: usize) -> Self {
+		let mut point = origin;
+		let mut rest = index;
+		for i in 0..N {
+			point[i] = origin[i] + step[i] * T::from_i64((rest % size[i]) as i64);
+			rest /= size[i];
+		}
+		point
+	}
+}
+
+/// A domain over which a noise function can be evaluated at `L` 'locations' at once using SIMD lanes, e.g. 4 or 8.
+#[cfg(feature = "simd")]
+pub trait NoiseBatch<Arg>: NoiseDomain<Arg> {
+	/// Get the values of the noise at several 'locations', exactly the same as calling `noise` at each.
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L];
+}
+
+/// A domain over which a noise function has an analytic derivative.
+pub trait NoiseDerivative<Arg>: NoiseDomain<Arg> {
+	/// The derivative of the noise with respect to the 'location', e.g. a gradient vector.
+	type Derivative;
+	
+	/// Get the value of the noise and its derivative at a particular 'location'.
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative);
 }
 
 /// A type of seed which can be split into multiple 'child' seeds.
@@ -42,7 +154,7 @@ pub trait SplitSeed {
 
 impl SplitSeed for u64 {
 	fn split(&self, n: usize) -> Self {
-		let mut hasher = WyHash::with_seed(*self);
+		let mut hasher = PortableHasher::with_seed(*self);
 		n.hash(&mut hasher);
 		hasher.finish()
 	}
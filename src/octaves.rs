@@ -1,4 +1,7 @@
-use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}, SumNoise, ScaleNoise};
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, SumNoise, ScaleNoise, RandomTransform, math::{Float, powi}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
 
 use core::ops::{Mul, AddAssign};
 use num_traits::Zero;
@@ -11,49 +14,92 @@ use higher_order_functions::Init;
 /// `persistence`: A multiplier for the persistence from one octave to the next.
 ///
 /// A good default is `Octaves::new(_, 0.5, 0.5)`.
+///
+/// Use `Octaves::decorrelated` to give each octave a pseudorandom rotation and offset so that lattice artifacts don't line up between octaves.
+///
+/// `T` is the floating point type of the scales, i.e. `f64` (the default) or `f32`.
 #[derive(Copy, Clone)]
-pub struct Octaves<Inner, const N: usize> {
-	inner: SumNoise<ScaleNoise<Inner, f64, f64>, N>,
+pub struct Octaves<Inner, const N: usize, T: Copy = f64> {
+	inner: SumNoise<ScaleNoise<Inner, T, T>, N>,
 }
 
-impl<Inner: Noise, const N: usize> Noise for Octaves<Inner, N> where
-	Inner::Value: Mul<f64>,
-	<Inner::Value as Mul<f64>>::Output: Zero + AddAssign,
+noise_ops!([Inner, const N: usize, T: Copy,] Octaves<Inner, N, T>);
+
+impl<Inner: Noise, const N: usize, T: Copy> Noise for Octaves<Inner, N, T> where
+	Inner::Value: Mul<T>,
+	<Inner::Value as Mul<T>>::Output: Zero + AddAssign,
 {
-	type Value = <SumNoise<ScaleNoise<Inner, f64, f64>, N> as Noise>::Value;
-	type Unseeded = Octaves<Inner::Unseeded, N>;
+	type Value = <SumNoise<ScaleNoise<Inner, T, T>, N> as Noise>::Value;
+	type Unseeded = Octaves<Inner::Unseeded, N, T>;
 }
 
-impl<Inner: Copy, const N: usize> Octaves<Inner, N> {
-	pub fn new(inner: Inner, lacunarity: f64, persistence: f64) -> Octaves<Inner, N> {
-		Octaves { inner: SumNoise::new(<[_; N]>::init(|i| ScaleNoise::new(inner, lacunarity.powi(i as i32), persistence.powi(i as i32)))) }
+impl<Inner: Copy, const N: usize, T: Float> Octaves<Inner, N, T> {
+	pub fn new(inner: Inner, lacunarity: T, persistence: T) -> Octaves<Inner, N, T> {
+		Octaves { inner: SumNoise::new(<[_; N]>::init(|i| ScaleNoise::new(inner, powi(lacunarity, i as i32), powi(persistence, i as i32)))) }
+	}
+	
+	/// Like `Octaves::new`, but each octave is rotated and offset by a [`RandomTransform`] derived from its seed.
+	pub fn decorrelated(inner: Inner, lacunarity: T, persistence: T) -> Octaves<RandomTransform<Inner, T>, N, T> {
+		Octaves::new(RandomTransform::new(inner), lacunarity, persistence)
 	}
 }
 
-impl<Inner: Seedable, const N: usize> Seedable for Octaves<Inner, N> where
+impl<Inner: Seedable, const N: usize, T: Copy> Seedable for Octaves<Inner, N, T> where
 	Inner::Seed: SplitSeed,
 {
-	type Seed = <SumNoise<ScaleNoise<Inner, f64, f64>, N> as Seedable>::Seed;
-	type Seeded = Octaves<Inner::Seeded, N>;
+	type Seed = <SumNoise<ScaleNoise<Inner, T, T>, N> as Seedable>::Seed;
+	type Seeded = Octaves<Inner::Seeded, N, T>;
 	
 	fn seed(self, seed: Self::Seed) -> Self::Seeded {
 		Octaves { inner: self.inner.seed(seed) }
 	}
 }
 
-impl<Inner: Seeded, const N: usize> Seeded for Octaves<Inner, N> where
+impl<Inner: Seeded, const N: usize, T: Copy> Seeded for Octaves<Inner, N, T> where
 	<Inner::Config as Seedable>::Seed: SplitSeed,
 {
-	type Config = Octaves<Inner::Config, N>;
+	type Config = Octaves<Inner::Config, N, T>;
 }
 
-impl<Arg: Copy, Inner: Noise, const N: usize> NoiseDomain<Arg> for Octaves<Inner, N> where
-	Arg: Mul<f64>,
+impl<Arg: Copy, Inner: Noise, const N: usize, T: Copy> NoiseDomain<Arg> for Octaves<Inner, N, T> where
+	Arg: Mul<T>,
 	Inner: NoiseDomain<Arg::Output>,
-	Inner::Value: Mul<f64>,
-	<Inner::Value as Mul<f64>>::Output: Zero + AddAssign,
+	Inner::Value: Mul<T>,
+	<Inner::Value as Mul<T>>::Output: Zero + AddAssign,
 {
 	fn noise(&self, arg: Arg) -> Self::Value {
 		self.inner.noise(arg)
 	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points)
+	}
+}
+
+impl<Arg: Copy, Inner: Noise, const N: usize, T: Copy> NoiseDerivative<Arg> for Octaves<Inner, N, T> where
+	Arg: Mul<T>,
+	Inner: NoiseDerivative<Arg::Output>,
+	Inner::Value: Mul<T>,
+	<Inner::Value as Mul<T>>::Output: Zero + AddAssign,
+	Inner::Derivative: Mul<T>,
+	<Inner::Derivative as Mul<T>>::Output: Mul<T>,
+	<<Inner::Derivative as Mul<T>>::Output as Mul<T>>::Output: Zero + AddAssign,
+{
+	type Derivative = <SumNoise<ScaleNoise<Inner, T, T>, N> as NoiseDerivative<Arg>>::Derivative;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		self.inner.noise_derivative(arg)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg: Copy, Inner: Noise, const N: usize, T: Copy> NoiseBatch<Arg> for Octaves<Inner, N, T> where
+	Arg: Mul<T>,
+	Inner: NoiseBatch<Arg::Output>,
+	Inner::Value: Mul<T>,
+	<Inner::Value as Mul<T>>::Output: Zero + AddAssign,
+{
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		self.inner.noise_batch(args)
+	}
 }
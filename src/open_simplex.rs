@@ -0,0 +1,343 @@
+use super::{Noise, NoiseDomain, Seedable, HashNoise, noise::Seeded, helpers::EmptyConfig, simplex::GRAD4, math::{Float, floor, round, sqrt, powi, dot}, ops::noise_ops};
+
+use core::{f64::consts::FRAC_1_SQRT_2, marker::PhantomData};
+
+use sized_matrix::Vector;
+
+use higher_order_functions::{Init, Map};
+
+use lazy_static::lazy_static;
+
+/// The orientation of the 3D lattice used by [`OpenSimplex2`] and [`OpenSimplex2S`].
+///
+/// This only affects 3D noise.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OpenSimplexOrientation {
+	/// Point the main diagonal of the lattice along `(1, 1, 1)`, which works when no axis is special.
+	Standard,
+	/// Rotate the lattice so that XY planes look isotropic, for when Z is vertical or represents time.
+	ImproveXY,
+	/// Rotate the lattice so that XZ planes look isotropic, for when Y is vertical or represents time.
+	ImproveXZ,
+}
+
+/// The OpenSimplex2 (fast) noise function, based on K.jpg's public domain implementation.
+///
+/// This avoids most of the directional artifacts of [`Simplex`](crate::Simplex) by using a rotated body-centred cubic lattice in 3D and five offset copies of the A4 lattice in 4D.
+///
+/// A good default is `OpenSimplex2::new(OpenSimplexOrientation::Standard)`.
+///
+/// `T` is the floating point type used for positions and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct OpenSimplex2<Inner, T = f64> {
+	inner: Inner,
+	orientation: OpenSimplexOrientation,
+	_phantom: PhantomData<fn() -> T>,
+}
+
+noise_ops!([Inner, T,] OpenSimplex2<Inner, T>);
+
+/// The OpenSimplex2S (smooth) noise function, based on K.jpg's public domain implementation.
+///
+/// This uses the same lattices as [`OpenSimplex2`] with a larger kernel radius, giving smoother results at a higher cost.
+///
+/// A good default is `OpenSimplex2S::new(OpenSimplexOrientation::Standard)`.
+///
+/// `T` is the floating point type used for positions and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct OpenSimplex2S<Inner, T = f64> {
+	inner: Inner,
+	orientation: OpenSimplexOrientation,
+	_phantom: PhantomData<fn() -> T>,
+}
+
+noise_ops!([Inner, T,] OpenSimplex2S<Inner, T>);
+
+impl<T: Float> OpenSimplex2<EmptyConfig<HashNoise>, T> {
+	pub fn new(orientation: OpenSimplexOrientation) -> Self {
+		OpenSimplex2 { inner: HashNoise::new(), orientation, _phantom: PhantomData }
+	}
+}
+
+impl<T: Float> OpenSimplex2S<EmptyConfig<HashNoise>, T> {
+	pub fn new(orientation: OpenSimplexOrientation) -> Self {
+		OpenSimplex2S { inner: HashNoise::new(), orientation, _phantom: PhantomData }
+	}
+}
+
+impl<Inner: Noise, T: Float> Noise for OpenSimplex2<Inner, T> {
+	type Value = T;
+	type Unseeded = OpenSimplex2<Inner::Unseeded, T>;
+}
+
+impl<Inner: Noise, T: Float> Noise for OpenSimplex2S<Inner, T> {
+	type Value = T;
+	type Unseeded = OpenSimplex2S<Inner::Unseeded, T>;
+}
+
+impl<Inner: Seedable, T> Seedable for OpenSimplex2<Inner, T> {
+	type Seed = Inner::Seed;
+	type Seeded = OpenSimplex2<Inner::Seeded, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		OpenSimplex2 { inner: self.inner.seed(seed), orientation: self.orientation, _phantom: PhantomData }
+	}
+}
+
+impl<Inner: Seedable, T> Seedable for OpenSimplex2S<Inner, T> {
+	type Seed = Inner::Seed;
+	type Seeded = OpenSimplex2S<Inner::Seeded, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		OpenSimplex2S { inner: self.inner.seed(seed), orientation: self.orientation, _phantom: PhantomData }
+	}
+}
+
+impl<Inner: Seeded, T> Seeded for OpenSimplex2<Inner, T> {
+	type Config = OpenSimplex2<Inner::Config, T>;
+}
+
+impl<Inner: Seeded, T> Seeded for OpenSimplex2S<Inner, T> {
+	type Config = OpenSimplex2S<Inner::Config, T>;
+}
+
+// (sqrt(3) - 1) / 2
+const SKEW_2D: f64 = 0.366025403784439;
+// (1 / sqrt(3) - 1) / 2
+const UNSKEW_2D: f64 = -0.21132486540518713;
+
+// 1 / sqrt(3)
+const ROOT3OVER3: f64 = 0.577350269189626;
+
+// (1 / sqrt(5) - 1) / 4
+const SKEW_4D: f64 = -0.138196601125011;
+// (sqrt(5) - 1) / 4
+const UNSKEW_4D: f64 = 0.309016994374947;
+
+// Scale factors bringing each variant's output to approximately [-1, 1].
+const NORM_2: f64 = 98.0;
+const NORM_3: f64 = 42.0;
+const NORM_4: f64 = 44.0;
+const NORM_2S: f64 = 18.0;
+const NORM_3S: f64 = 12.0;
+const NORM_4S: f64 = 8.5;
+
+fn normalise<const N: usize>(grad: [f64; N]) -> Vector<f64, N> {
+	let grad = Vector::vector(grad);
+	grad / sqrt(dot(grad, grad))
+}
+
+lazy_static! {
+	static ref GRAD2: [Vector<f64, 2>; 24] = [
+		[1., 0.], [0.9659258262890683, 0.25881904510252074], [0.8660254037844387, 0.5], [FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+		[0.5, 0.8660254037844387], [0.25881904510252074, 0.9659258262890683], [0., 1.], [-0.25881904510252074, 0.9659258262890683],
+		[-0.5, 0.8660254037844387], [-FRAC_1_SQRT_2, FRAC_1_SQRT_2], [-0.8660254037844387, 0.5], [-0.9659258262890683, 0.25881904510252074],
+		[-1., 0.], [-0.9659258262890683, -0.25881904510252074], [-0.8660254037844387, -0.5], [-FRAC_1_SQRT_2, -FRAC_1_SQRT_2],
+		[-0.5, -0.8660254037844387], [-0.25881904510252074, -0.9659258262890683], [0., -1.], [0.25881904510252074, -0.9659258262890683],
+		[0.5, -0.8660254037844387], [FRAC_1_SQRT_2, -FRAC_1_SQRT_2], [0.8660254037844387, -0.5], [0.9659258262890683, -0.25881904510252074],
+	].map(Vector::vector);
+	
+	// The vertices of a rhombicuboctahedron.
+	static ref GRAD3: [Vector<f64, 3>; 24] = [
+		[1., 1., 2.414213562373095], [-1., 1., 2.414213562373095], [1., -1., 2.414213562373095], [-1., -1., 2.414213562373095],
+		[1., 1., -2.414213562373095], [-1., 1., -2.414213562373095], [1., -1., -2.414213562373095], [-1., -1., -2.414213562373095],
+		[1., 2.414213562373095, 1.], [-1., 2.414213562373095, 1.], [1., -2.414213562373095, 1.], [-1., -2.414213562373095, 1.],
+		[1., 2.414213562373095, -1.], [-1., 2.414213562373095, -1.], [1., -2.414213562373095, -1.], [-1., -2.414213562373095, -1.],
+		[2.414213562373095, 1., 1.], [-2.414213562373095, 1., 1.], [2.414213562373095, -1., 1.], [-2.414213562373095, -1., 1.],
+		[2.414213562373095, 1., -1.], [-2.414213562373095, 1., -1.], [2.414213562373095, -1., -1.], [-2.414213562373095, -1., -1.],
+	].map(normalise);
+	
+	// The edges and corners of a tesseract.
+	static ref GRAD4_OS: [Vector<f64, 4>; 48] = <[_; 48]>::init(|i| {
+		if i < 32 { normalise([GRAD4[i][0], GRAD4[i][1], GRAD4[i][2], GRAD4[i][3]]) }
+		else { Vector::init(|j: usize| if (i >> j) & 1 == 1 { -0.5 } else { 0.5 }) }
+	});
+}
+
+// The gradient tables are only stored as `f64`, and converted exactly when used in `f64`.
+fn contribution<Inner, T: Float, const N: usize>(inner: &Inner, grads: &[Vector<f64, N>], r_squared: T, vertex: Vector<i64, N>, rel: Vector<T, N>) -> T where
+	Inner: NoiseDomain<Vector<i64, N>, Value = u64>,
+{
+	let t = r_squared - dot(rel, rel);
+	if t <= T::zero() { T::zero() }
+	else {
+		let p = inner.noise(vertex) % grads.len() as u64;
+		powi(t, 4) * dot(rel, grads[p as usize].map(T::from_f64))
+	}
+}
+
+fn noise_2<Inner: NoiseDomain<Vector<i64, 2>, Value = u64>, T: Float>(inner: &Inner, pos: Vector<T, 2>, smooth: bool) -> T {
+	let s = (pos[0] + pos[1]) * T::from_f64(SKEW_2D);
+	let base = pos.map(|x| floor(x + s));
+	let rel = pos.map(|x| x + s) - base;
+	let base = base.map(T::to_i64);
+	
+	let offsets: &[[i64; 2]] =
+		if smooth { &[[0, 0], [1, 0], [0, 1], [1, 1], [-1, 0], [0, -1], [2, 1], [1, 2]] }
+		else if rel[0] >= rel[1] { &[[0, 0], [1, 0], [1, 1]] }
+		else { &[[0, 0], [0, 1], [1, 1]] };
+	
+	let r_squared = T::from_f64(if smooth { 2. / 3. } else { 0.5 });
+	
+	let mut value = T::zero();
+	for &offset in offsets {
+		let offset = Vector::vector(offset);
+		let rel = rel - offset.map(T::from_i64);
+		let t = (rel[0] + rel[1]) * T::from_f64(UNSKEW_2D);
+		value += contribution(inner, &*GRAD2, r_squared, base + offset, rel.map(|x| x + t));
+	}
+	value
+}
+
+fn rotate_3<T: Float>(orientation: OpenSimplexOrientation, pos: Vector<T, 3>) -> Vector<T, 3> {
+	match orientation {
+		OpenSimplexOrientation::Standard => {
+			let r = (pos[0] + pos[1] + pos[2]) * T::from_f64(2. / 3.);
+			pos.map(|x| r - x)
+		},
+		OpenSimplexOrientation::ImproveXY => {
+			let xy = pos[0] + pos[1];
+			let s = xy * T::from_f64(UNSKEW_2D);
+			let z = pos[2] * T::from_f64(ROOT3OVER3);
+			Vector::vector([pos[0] + s + z, pos[1] + s + z, xy * T::from_f64(-ROOT3OVER3) + z])
+		},
+		OpenSimplexOrientation::ImproveXZ => {
+			let xz = pos[0] + pos[2];
+			let s = xz * T::from_f64(UNSKEW_2D);
+			let y = pos[1] * T::from_f64(ROOT3OVER3);
+			Vector::vector([pos[0] + s + y, xz * T::from_f64(-ROOT3OVER3) + y, pos[2] + s + y])
+		},
+	}
+}
+
+fn noise_3<Inner: NoiseDomain<Vector<i64, 3>, Value = u64>, T: Float>(inner: &Inner, pos: Vector<T, 3>, smooth: bool) -> T {
+	let mut value = T::zero();
+	
+	// The body-centred cubic lattice is made up of two cubic lattices offset by half a cell.
+	// Vertices are identified by doubling their coordinates so that each copy is distinct.
+	for copy in 0..2 {
+		let pos = pos.map(|x| x - T::from_f64(0.5 * copy as f64));
+		let id = |vertex: Vector<i64, 3>| vertex.map(|v| 2 * v + copy);
+		
+		if smooth {
+			let base = pos.map(floor);
+			let rel = pos - base;
+			let base = base.map(T::to_i64);
+			
+			for corner in 0..8 {
+				let offset = Vector::<i64, 3>::init(|i: usize| (corner >> i) & 1);
+				value += contribution(inner, &*GRAD3, T::from_f64(0.75), id(base + offset), rel - offset.map(T::from_i64));
+			}
+		} else {
+			let nearest = pos.map(round);
+			let rel = pos - nearest;
+			let nearest = nearest.map(T::to_i64);
+			
+			value += contribution(inner, &*GRAD3, T::from_f64(0.6), id(nearest), rel);
+			
+			let axis =
+				if rel[0].abs() >= rel[1].abs() && rel[0].abs() >= rel[2].abs() { 0 }
+				else if rel[1].abs() >= rel[2].abs() { 1 }
+				else { 2 };
+			let offset = Vector::<i64, 3>::init(|i: usize| if i != axis { 0 } else if rel[i] >= T::zero() { 1 } else { -1 });
+			value += contribution(inner, &*GRAD3, T::from_f64(0.6), id(nearest + offset), rel - offset.map(T::from_i64));
+		}
+	}
+	
+	value
+}
+
+fn noise_4<Inner: NoiseDomain<Vector<i64, 4>, Value = u64>, T: Float>(inner: &Inner, pos: Vector<T, 4>, smooth: bool) -> T {
+	let s = (pos[0] + pos[1] + pos[2] + pos[3]) * T::from_f64(SKEW_4D);
+	let skewed = pos.map(|x| x + s);
+	
+	let unskew = |rel: Vector<T, 4>| {
+		let t = (rel[0] + rel[1] + rel[2] + rel[3]) * T::from_f64(UNSKEW_4D);
+		rel.map(|x| x + t)
+	};
+	
+	// The lattice is made up of five copies of the A4 lattice, each offset by a fifth of a cell along the main diagonal.
+	// Vertices are identified by scaling their coordinates by five so that each copy is distinct.
+	let id = |vertex: Vector<i64, 4>, copy: i64| vertex.map(|v| 5 * v + copy);
+	
+	let mut value = T::zero();
+	
+	if smooth {
+		for copy in 0..5 {
+			let pos = skewed.map(|x| x - T::from_f64(0.2 * copy as f64));
+			let base = pos.map(floor);
+			let rel = pos - base;
+			let base = base.map(T::to_i64);
+			
+			for corner in 0..16 {
+				let offset = Vector::<i64, 4>::init(|i: usize| (corner >> i) & 1);
+				value += contribution(inner, &*GRAD4_OS, T::from_f64(0.8), id(base + offset, copy), unskew(rel - offset.map(T::from_i64)));
+			}
+		}
+	} else {
+		let base = skewed.map(floor);
+		let mut rel = skewed - base;
+		let mut vertex = base.map(T::to_i64);
+		
+		// Start on the copy most likely to have a contributing vertex in the base simplex of its cell.
+		let starting_copy = ((rel[0] + rel[1] + rel[2] + rel[3]) * T::from_f64(1.25)).to_i64();
+		rel = rel.map(|x| x - T::from_f64(0.2 * starting_copy as f64));
+		
+		for copy in (starting_copy - 4..=starting_copy).rev() {
+			// Move to the closest vertex of the simplex whose base vertex is the current vertex.
+			let score = T::one() - (rel[0] + rel[1] + rel[2] + rel[3]);
+			let mut axis = None;
+			for i in 0..4 {
+				if rel[i] >= score && axis.is_none_or(|j: usize| rel[i] > rel[j]) {
+					axis = Some(i);
+				}
+			}
+			if let Some(axis) = axis {
+				vertex[axis] += 1;
+				rel[axis] = rel[axis] - T::one();
+			}
+			
+			value += contribution(inner, &*GRAD4_OS, T::from_f64(0.6), id(vertex, copy), unskew(rel));
+			
+			rel = rel.map(|x| x + T::from_f64(0.2));
+		}
+	}
+	
+	value
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 2>, Value = u64>, T: Float> NoiseDomain<Vector<T, 2>> for OpenSimplex2<Inner, T> {
+	fn noise(&self, pos: Vector<T, 2>) -> T {
+		T::from_f64(NORM_2) * noise_2(&self.inner, pos, false)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 3>, Value = u64>, T: Float> NoiseDomain<Vector<T, 3>> for OpenSimplex2<Inner, T> {
+	fn noise(&self, pos: Vector<T, 3>) -> T {
+		T::from_f64(NORM_3) * noise_3(&self.inner, rotate_3(self.orientation, pos), false)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 4>, Value = u64>, T: Float> NoiseDomain<Vector<T, 4>> for OpenSimplex2<Inner, T> {
+	fn noise(&self, pos: Vector<T, 4>) -> T {
+		T::from_f64(NORM_4) * noise_4(&self.inner, pos, false)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 2>, Value = u64>, T: Float> NoiseDomain<Vector<T, 2>> for OpenSimplex2S<Inner, T> {
+	fn noise(&self, pos: Vector<T, 2>) -> T {
+		T::from_f64(NORM_2S) * noise_2(&self.inner, pos, true)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 3>, Value = u64>, T: Float> NoiseDomain<Vector<T, 3>> for OpenSimplex2S<Inner, T> {
+	fn noise(&self, pos: Vector<T, 3>) -> T {
+		T::from_f64(NORM_3S) * noise_3(&self.inner, rotate_3(self.orientation, pos), true)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 4>, Value = u64>, T: Float> NoiseDomain<Vector<T, 4>> for OpenSimplex2S<Inner, T> {
+	fn noise(&self, pos: Vector<T, 4>) -> T {
+		T::from_f64(NORM_4S) * noise_4(&self.inner, pos, true)
+	}
+}
@@ -0,0 +1,71 @@
+/// A noise function or configuration which can be combined with the arithmetic operators.
+///
+/// `a + b`, `a - b`, `a * b`, and `-a` give an [`AddNoise`], [`SubNoise`], [`MulNoise`], and [`NegNoise`], seeded the same way as
+/// calling their `new`. Multiplying a noise function with floating point values by an `f64`, e.g. `a * 0.5`, gives a [`ScaleValue`]
+/// which only scales the output, in the noise function's own float type.
+///
+/// Implement this for your own noise functions to use them on the right hand side of the operators, and with [`NoiseExt`](super::NoiseExt).
+///
+/// Every noise function and configuration in this crate implements this, through a `noise_ops!` invocation next to its definition.
+/// `tests/operand.rs` checks each exported type, so a new type must be added there too.
+///
+/// [`AddNoise`]: super::AddNoise
+/// [`SubNoise`]: super::SubNoise
+/// [`MulNoise`]: super::MulNoise
+/// [`NegNoise`]: super::NegNoise
+/// [`ScaleValue`]: super::ScaleValue
+pub trait NoiseOperand { }
+
+// Rust doesn't allow implementing the operators for every `NoiseOperand` at once, so each type opts in with this next to its
+// definition. Types whose values are never floating point are marked `unscaled` and don't get `* f64`, and the few that can
+// check their value type get their own `* f64` impl.
+macro_rules! noise_ops {
+	([$($generics:tt)*] $type:ty $(where [$($bounds:tt)*])?) => {
+		noise_ops!(unscaled [$($generics)*] $type $(where [$($bounds)*])?);
+		
+		impl<$($generics)*> core::ops::Mul<f64> for $type $(where $($bounds)*)? {
+			type Output = $crate::ScaleValue<Self>;
+			
+			fn mul(self, rhs: f64) -> $crate::ScaleValue<Self> {
+				$crate::ScaleValue::new(self, rhs)
+			}
+		}
+	};
+	(unscaled [$($generics:tt)*] $type:ty $(where [$($bounds:tt)*])?) => {
+		impl<$($generics)*> $crate::NoiseOperand for $type $(where $($bounds)*)? { }
+		
+		impl<$($generics)* Rhs: $crate::NoiseOperand> core::ops::Add<Rhs> for $type $(where $($bounds)*)? {
+			type Output = $crate::AddNoise<Self, Rhs>;
+			
+			fn add(self, rhs: Rhs) -> $crate::AddNoise<Self, Rhs> {
+				$crate::AddNoise::new(self, rhs)
+			}
+		}
+		
+		impl<$($generics)* Rhs: $crate::NoiseOperand> core::ops::Sub<Rhs> for $type $(where $($bounds)*)? {
+			type Output = $crate::SubNoise<Self, Rhs>;
+			
+			fn sub(self, rhs: Rhs) -> $crate::SubNoise<Self, Rhs> {
+				$crate::SubNoise::new(self, rhs)
+			}
+		}
+		
+		impl<$($generics)* Rhs: $crate::NoiseOperand> core::ops::Mul<Rhs> for $type $(where $($bounds)*)? {
+			type Output = $crate::MulNoise<Self, Rhs>;
+			
+			fn mul(self, rhs: Rhs) -> $crate::MulNoise<Self, Rhs> {
+				$crate::MulNoise::new(self, rhs)
+			}
+		}
+		
+		impl<$($generics)*> core::ops::Neg for $type $(where $($bounds)*)? {
+			type Output = $crate::NegNoise<Self>;
+			
+			fn neg(self) -> $crate::NegNoise<Self> {
+				$crate::NegNoise::new(self)
+			}
+		}
+	};
+}
+
+pub(crate) use noise_ops;
@@ -0,0 +1,96 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, math::{Float, sin_cos}, ops::noise_ops};
+
+use core::f64::consts::TAU;
+
+use sized_matrix::Vector;
+
+use higher_order_functions::Init;
+
+/// A noise function which tiles seamlessly, repeating every `period` along each axis.
+///
+/// Each axis is mapped onto a circle in a noise function with twice as many dimensions, e.g. a tileable 2D texture samples 4D noise.
+/// The circles have circumference equal to the period, so features are roughly the same size as in the inner noise function.
+/// The result always tiles seamlessly, since the mapping onto the circles is continuous, and it is as smooth as the inner noise function.
+///
+/// [`Simplex`](super::Simplex) uses a larger kernel in 3D and 4D which makes it slightly discontinuous everywhere, so a 2D `Periodic`
+/// of it is too. Prefer [`Perlin`](super::Perlin) or [`OpenSimplex2S`](super::OpenSimplex2S) there. `Simplex` is continuous in 2D and 6D,
+/// and is the only built-in noise function with enough dimensions for a 3D `Periodic`.
+///
+/// `T` is the floating point type of the coordinates, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct Periodic<Inner, const N: usize, T: Copy = f64> {
+	inner: Inner,
+	period: Vector<T, N>,
+}
+
+noise_ops!([Inner, const N: usize, T: Copy,] Periodic<Inner, N, T>);
+
+impl<Inner: Noise, const N: usize, T: Copy> Noise for Periodic<Inner, N, T> {
+	type Value = Inner::Value;
+	type Unseeded = Periodic<Inner::Unseeded, N, T>;
+}
+
+impl<Inner, const N: usize, T: Float> Periodic<Inner, N, T> {
+	pub fn new(inner: Inner, period: Vector<T, N>) -> Periodic<Inner, N, T> {
+		Periodic { inner, period }
+	}
+	
+	fn torus<const M: usize>(&self, pos: Vector<T, N>) -> Vector<T, M> {
+		let tau = T::from_f64(TAU);
+		let circles = <[[T; 2]; N]>::init(|i| {
+			let (sin, cos) = sin_cos(tau * pos[i] / self.period[i]);
+			let radius = self.period[i] / tau;
+			[radius * cos, radius * sin]
+		});
+		Vector::init(|i: usize| circles[i / 2][i % 2])
+	}
+}
+
+impl<Inner: Seedable, const N: usize, T: Copy> Seedable for Periodic<Inner, N, T> {
+	type Seed = Inner::Seed;
+	type Seeded = Periodic<Inner::Seeded, N, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Periodic { inner: self.inner.seed(seed), period: self.period }
+	}
+}
+
+impl<Inner: Seeded, const N: usize, T: Copy> Seeded for Periodic<Inner, N, T> {
+	type Config = Periodic<Inner::Config, N, T>;
+}
+
+impl<Inner: NoiseDomain<Vector<T, 2>>, T: Float> NoiseDomain<T> for Periodic<Inner, 1, T> {
+	fn noise(&self, x: T) -> Self::Value {
+		self.noise(Vector::vector([x]))
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<T, 2>>, T: Float> NoiseDomain<Vector<T, 1>> for Periodic<Inner, 1, T> {
+	fn noise(&self, pos: Vector<T, 1>) -> Self::Value {
+		self.inner.noise(self.torus(pos))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Vector<T, 1>> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points.map(|pos| self.torus(pos)))
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<T, 4>>, T: Float> NoiseDomain<Vector<T, 2>> for Periodic<Inner, 2, T> {
+	fn noise(&self, pos: Vector<T, 2>) -> Self::Value {
+		self.inner.noise(self.torus(pos))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Vector<T, 2>> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points.map(|pos| self.torus(pos)))
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<T, 6>>, T: Float> NoiseDomain<Vector<T, 3>> for Periodic<Inner, 3, T> {
+	fn noise(&self, pos: Vector<T, 3>) -> Self::Value {
+		self.inner.noise(self.torus(pos))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Vector<T, 3>> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points.map(|pos| self.torus(pos)))
+	}
+}
@@ -0,0 +1,100 @@
+use super::{NoiseDomain, HashNoise, helpers::{SeedOnlyNoise, EmptyConfig}, simplex::{GRAD3_INT, GRAD4_INT}, math::{Float, floor, dot}, ops::noise_ops};
+
+use sized_matrix::Vector;
+
+use higher_order_functions::{Init, Map};
+
+use core::marker::PhantomData;
+
+/// A classic (improved) Perlin gradient noise function.
+///
+/// Gradients are chosen by hashing the lattice corners with [`HashNoise`], and blended using the quintic fade curve `6t^5 - 15t^4 + 10t^3`.
+///
+/// `T` is the floating point type used for positions and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct Perlin<T = f64> {
+	inner: HashNoise,
+	_phantom: PhantomData<fn() -> T>,
+}
+
+noise_ops!([T,] Perlin<T>);
+
+impl<T: Float> Perlin<T> {
+	pub fn new() -> EmptyConfig<Self> {
+		EmptyConfig::new()
+	}
+}
+
+impl<T: Float> SeedOnlyNoise for Perlin<T> {
+	type Seed = u64;
+	type Value = T;
+	
+	fn seed(seed: u64) -> Self {
+		Self {
+			inner: HashNoise::seed(seed),
+			_phantom: PhantomData,
+		}
+	}
+}
+
+fn fade<T: Float>(t: T) -> T {
+	t * t * t * (t * (t * T::from_f64(6.) - T::from_f64(15.)) + T::from_f64(10.))
+}
+
+fn gradient<T: Float, const N: usize>(grad: &[i8]) -> Vector<T, N> {
+	Vector::init(|i: usize| T::from_i64(i64::from(grad[i])))
+}
+
+fn perlin<T: Float, const N: usize, Grad: Fn(u64, Vector<T, N>) -> T>(inner: &HashNoise, pos: Vector<T, N>, grad: Grad) -> T {
+	let base = pos.map(floor);
+	let rel = pos - base;
+	let fades = rel.map(fade);
+	
+	let base = base.map(T::to_i64);
+	
+	let mut sum = T::zero();
+	for corner in 0..(1usize << N) {
+		let offset = Vector::<i64, N>::init(|i: usize| ((corner >> i) & 1) as i64);
+		
+		let mut weight = T::one();
+		for i in 0..N {
+			weight = weight * if offset[i] == 1 { fades[i] } else { T::one() - fades[i] };
+		}
+		
+		sum += weight * grad(inner.noise(base + offset), rel - offset.map(T::from_i64));
+	}
+	sum
+}
+
+impl<T: Float> NoiseDomain<Vector<T, 1>> for Perlin<T> {
+	fn noise(&self, pos: Vector<T, 1>) -> T {
+		T::from_f64(0.25) * perlin(&self.inner, pos, |h, rel| {
+			let g = T::from_i64(1 + (h & 7) as i64);
+			if h & 8 == 0 { g * rel[0] } else { -g * rel[0] }
+		})
+	}
+}
+
+impl<T: Float> NoiseDomain<T> for Perlin<T> {
+	fn noise(&self, x: T) -> T {
+		self.noise(Vector::vector([x]))
+	}
+}
+
+impl<T: Float> NoiseDomain<Vector<T, 2>> for Perlin<T> {
+	fn noise(&self, pos: Vector<T, 2>) -> T {
+		perlin(&self.inner, pos, |h, rel| dot(rel, gradient(&GRAD3_INT[(h % 12) as usize])))
+	}
+}
+
+impl<T: Float> NoiseDomain<Vector<T, 3>> for Perlin<T> {
+	fn noise(&self, pos: Vector<T, 3>) -> T {
+		perlin(&self.inner, pos, |h, rel| dot(rel, gradient(&GRAD3_INT[(h % 12) as usize])))
+	}
+}
+
+impl<T: Float> NoiseDomain<Vector<T, 4>> for Perlin<T> {
+	fn noise(&self, pos: Vector<T, 4>) -> T {
+		perlin(&self.inner, pos, |h, rel| dot(rel, gradient(&GRAD4_INT[(h % 32) as usize])))
+	}
+}
@@ -0,0 +1,45 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, math::Float, ops::noise_ops};
+
+/// A noise function which raises the values of another noise function to a power.
+///
+/// `noise(x) = inner.noise(x)^exponent`
+///
+/// Negative values raised to a non-integer exponent give NaN, so wrap the inner noise in [`Abs`](super::Abs) or
+/// [`Clamp`](super::Clamp) first if it can be negative.
+#[derive(Copy, Clone)]
+pub struct Pow<Inner, T = f64> {
+	inner: Inner,
+	exponent: T,
+}
+
+noise_ops!([Inner, T,] Pow<Inner, T>);
+
+impl<Inner: Noise<Value = T>, T: Float> Noise for Pow<Inner, T> {
+	type Value = T;
+	type Unseeded = Pow<Inner::Unseeded, T>;
+}
+
+impl<Inner, T> Pow<Inner, T> {
+	pub fn new(inner: Inner, exponent: T) -> Pow<Inner, T> {
+		Pow { inner, exponent }
+	}
+}
+
+impl<Inner: Seedable, T> Seedable for Pow<Inner, T> {
+	type Seed = Inner::Seed;
+	type Seeded = Pow<Inner::Seeded, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Pow { inner: self.inner.seed(seed), exponent: self.exponent }
+	}
+}
+
+impl<Inner: Seeded, T> Seeded for Pow<Inner, T> {
+	type Config = Pow<Inner::Config, T>;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg, Value = T>, T: Float> NoiseDomain<Arg> for Pow<Inner, T> {
+	fn noise(&self, arg: Arg) -> T {
+		self.inner.noise(arg).powf(self.exponent)
+	}
+}
@@ -0,0 +1,154 @@
+use super::{Noise, NoiseDomain, Seedable, HashNoise, noise::Seeded, helpers::SeedOnlyNoise, to_float::bits_to_f64, math::{Float, sqrt, dot, mul}, ops::noise_ops};
+
+use core::hash::Hash;
+
+use sized_matrix::{Vector, Matrix};
+
+use higher_order_functions::{Init, Section};
+
+use num_traits::{Zero, One};
+
+/// A noise function which applies a pseudorandom rotation and translation to its input, derived from the seed.
+///
+/// `noise(x) = inner.noise(rotation * x + offset)`
+///
+/// The inner noise function is seeded with the same seed, so this only changes the orientation and position of the noise.
+/// This is used by [`Octaves::decorrelated`](crate::Octaves::decorrelated) to stop lattice artifacts lining up between octaves.
+///
+/// `T` is the floating point type of the positions, i.e. `f64` (the default) or `f32`. The transform is always generated in `f64`.
+#[derive(Copy, Clone)]
+pub struct RandomTransform<Inner, T = f64> {
+	inner: Inner,
+	rotation_2: Matrix<T, 2, 2>,
+	rotation_3: Matrix<T, 3, 3>,
+	rotation_4: Matrix<T, 4, 4>,
+	offset: Vector<T, 4>,
+}
+
+noise_ops!([Inner, T,] RandomTransform<Inner, T>);
+
+impl<Inner: Noise, T> Noise for RandomTransform<Inner, T> {
+	type Value = Inner::Value;
+	type Unseeded = RandomTransform<Inner::Unseeded, T>;
+}
+
+impl<Inner, T: Float> RandomTransform<Inner, T> {
+	pub fn new(inner: Inner) -> RandomTransform<Inner, T> {
+		RandomTransform {
+			inner,
+			rotation_2: Matrix::one(),
+			rotation_3: Matrix::one(),
+			rotation_4: Matrix::one(),
+			offset: Vector::zero(),
+		}
+	}
+}
+
+// The range of the random offsets in each axis.
+const OFFSET_RANGE: f64 = 16.;
+
+fn unit_quaternion(q: Vector<f64, 4>) -> Vector<f64, 4> {
+	let length = sqrt(dot(q, q));
+	if length < 1e-6 { Vector::vector([1., 0., 0., 0.]) }
+	else { q / length }
+}
+
+fn convert<T: Float, const M: usize, const N: usize>(matrix: Matrix<f64, M, N>) -> Matrix<T, M, N> {
+	Matrix::init(|index: [usize; 2]| T::from_f64(matrix[index]))
+}
+
+impl<Inner: Seedable, T: Float> Seedable for RandomTransform<Inner, T> where Inner::Seed: Hash {
+	type Seed = Inner::Seed;
+	type Seeded = RandomTransform<Inner::Seeded, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		let hash = HashNoise::seed(0x52414e444f4d5452);
+		let random = |i: usize| 2. * bits_to_f64(hash.noise((&seed, i))) - 1.;
+		
+		let a = unit_quaternion(Vector::init(|i: usize| random(i)));
+		let b = unit_quaternion(Vector::init(|i: usize| random(i + 4)));
+		let offset = Vector::init(|i: usize| T::from_f64(OFFSET_RANGE * random(i + 8)));
+		
+		let rotation_2: Matrix<f64, 2, 2> = {
+			let c: Vector<f64, 2> = unit_quaternion(Vector::vector([random(12), random(13), 0., 0.])).section(0);
+			Matrix::rows([
+				[c[0], -c[1]],
+				[c[1], c[0]],
+			])
+		};
+		
+		let rotation_3: Matrix<f64, 3, 3> = {
+			let [w, x, y, z] = [a[0], a[1], a[2], a[3]];
+			Matrix::rows([
+				[1. - 2. * (y * y + z * z), 2. * (x * y - w * z), 2. * (x * z + w * y)],
+				[2. * (x * y + w * z), 1. - 2. * (x * x + z * z), 2. * (y * z - w * x)],
+				[2. * (x * z - w * y), 2. * (y * z + w * x), 1. - 2. * (x * x + y * y)],
+			])
+		};
+		
+		// Any 4D rotation can be written as `x -> a * x * b` for unit quaternions `a` and `b`.
+		let rotation_4 = mul(Matrix::rows([
+			[a[0], -a[1], -a[2], -a[3]],
+			[a[1], a[0], -a[3], a[2]],
+			[a[2], a[3], a[0], -a[1]],
+			[a[3], -a[2], a[1], a[0]],
+		]), Matrix::rows([
+			[b[0], -b[1], -b[2], -b[3]],
+			[b[1], b[0], b[3], -b[2]],
+			[b[2], -b[3], b[0], b[1]],
+			[b[3], b[2], -b[1], b[0]],
+		]));
+		
+		RandomTransform {
+			inner: self.inner.seed(seed),
+			rotation_2: convert(rotation_2),
+			rotation_3: convert(rotation_3),
+			rotation_4: convert(rotation_4),
+			offset,
+		}
+	}
+}
+
+impl<Inner: Seeded, T: Float> Seeded for RandomTransform<Inner, T> where <Inner::Config as Seedable>::Seed: Hash {
+	type Config = RandomTransform<Inner::Config, T>;
+}
+
+impl<Inner: NoiseDomain<Vector<T, 1>>, T: Float> NoiseDomain<Vector<T, 1>> for RandomTransform<Inner, T> {
+	fn noise(&self, pos: Vector<T, 1>) -> Self::Value {
+		self.inner.noise(pos + self.offset.section(0))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Vector<T, 1>> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points.map(|pos| pos + self.offset.section(0)))
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<T, 2>>, T: Float> NoiseDomain<Vector<T, 2>> for RandomTransform<Inner, T> {
+	fn noise(&self, pos: Vector<T, 2>) -> Self::Value {
+		self.inner.noise(mul(self.rotation_2, pos) + self.offset.section(0))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Vector<T, 2>> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points.map(|pos| mul(self.rotation_2, pos) + self.offset.section(0)))
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<T, 3>>, T: Float> NoiseDomain<Vector<T, 3>> for RandomTransform<Inner, T> {
+	fn noise(&self, pos: Vector<T, 3>) -> Self::Value {
+		self.inner.noise(mul(self.rotation_3, pos) + self.offset.section(0))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Vector<T, 3>> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points.map(|pos| mul(self.rotation_3, pos) + self.offset.section(0)))
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<T, 4>>, T: Float> NoiseDomain<Vector<T, 4>> for RandomTransform<Inner, T> {
+	fn noise(&self, pos: Vector<T, 4>) -> Self::Value {
+		self.inner.noise(mul(self.rotation_4, pos) + self.offset)
+	}
+	
+	fn noise_points<Points: Iterator<Item = Vector<T, 4>> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner.noise_points(points.map(|pos| mul(self.rotation_4, pos) + self.offset))
+	}
+}
@@ -0,0 +1,74 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}, layers::Layers, math::Float, ops::noise_ops};
+
+use core::ops::Mul;
+
+/// A ridged multifractal noise function, based on Musgrave's algorithm.
+///
+/// Each octave is folded into sharp ridges with `(offset - |noise|)^2`, and weighted by the value of the previous octave so that detail accumulates along the ridges.
+///
+/// `lacunarity`: A multiplier for the frequency from one octave to the next.
+///
+/// `persistence`: A multiplier for the amplitude from one octave to the next.
+///
+/// `offset`: The height of the ridges.
+///
+/// `gain`: How strongly each octave's value weights the next octave.
+///
+/// A good default is `RidgedMulti::new(_, 2.0, 0.5, 1.0, 2.0)`.
+///
+/// `T` is the floating point type of the parameters and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct RidgedMulti<Inner, const N: usize, T = f64> {
+	layers: Layers<Inner, N, T>,
+	offset: T,
+	gain: T,
+}
+
+noise_ops!([Inner, const N: usize, T,] RidgedMulti<Inner, N, T>);
+
+impl<Inner: Noise, const N: usize, T: Float> Noise for RidgedMulti<Inner, N, T> {
+	type Value = T;
+	type Unseeded = RidgedMulti<Inner::Unseeded, N, T>;
+}
+
+impl<Inner: Copy, const N: usize, T: Float> RidgedMulti<Inner, N, T> {
+	pub fn new(inner: Inner, lacunarity: T, persistence: T, offset: T, gain: T) -> RidgedMulti<Inner, N, T> {
+		RidgedMulti {
+			layers: Layers::new(inner, lacunarity, persistence),
+			offset,
+			gain,
+		}
+	}
+}
+
+impl<Inner: Seedable, const N: usize, T> Seedable for RidgedMulti<Inner, N, T> where Inner::Seed: SplitSeed {
+	type Seed = Inner::Seed;
+	type Seeded = RidgedMulti<Inner::Seeded, N, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		RidgedMulti {
+			layers: self.layers.seed(seed),
+			offset: self.offset,
+			gain: self.gain,
+		}
+	}
+}
+
+impl<Inner: Seeded, const N: usize, T> Seeded for RidgedMulti<Inner, N, T> where <Inner::Config as Seedable>::Seed: SplitSeed {
+	type Config = RidgedMulti<Inner::Config, N, T>;
+}
+
+impl<Arg: Copy + Mul<T>, Inner: NoiseDomain<Arg::Output, Value = T>, const N: usize, T: Float> NoiseDomain<Arg> for RidgedMulti<Inner, N, T> {
+	fn noise(&self, arg: Arg) -> T {
+		let mut sum = T::zero();
+		let mut weight = T::one();
+		for (value, amplitude) in self.layers.octaves(arg) {
+			let signal = self.offset - value.abs();
+			let signal = signal * signal * weight;
+			sum += signal * amplitude;
+			weight = signal * self.gain;
+			weight = if weight < T::zero() { T::zero() } else if weight > T::one() { T::one() } else { weight };
+		}
+		sum
+	}
+}
@@ -1,4 +1,7 @@
-use super::{Noise, NoiseDomain, Seedable, noise::Seeded};
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, NoiseDerivative}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
 
 use core::ops::Mul;
 
@@ -12,6 +15,8 @@ pub struct ScaleNoise<Inner, InScale: Copy, OutScale: Copy> {
 	scale_out: OutScale,
 }
 
+noise_ops!([Inner, InScale: Copy, OutScale: Copy,] ScaleNoise<Inner, InScale, OutScale>);
+
 impl<Inner: Noise, InScale: Copy, OutScale: Copy> Noise for ScaleNoise<Inner, InScale, OutScale> where Inner::Value: Mul<OutScale> {
 	type Value = <Inner::Value as Mul<OutScale>>::Output;
 	type Unseeded = ScaleNoise<Inner::Unseeded, InScale, OutScale>;
@@ -44,4 +49,35 @@ impl<Arg, Inner, InScale: Copy, OutScale: Copy> NoiseDomain<Arg> for ScaleNoise<
 	fn noise(&self, arg: Arg) -> Self::Value {
 		self.inner.noise(arg * self.scale_in) * self.scale_out
 	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		let (scale_in, scale_out) = (self.scale_in, self.scale_out);
+		self.inner.noise_points(points.map(move |arg| arg * scale_in)).map(move |value| value * scale_out)
+	}
+}
+
+impl<Arg, Inner, InScale: Copy, OutScale: Copy> NoiseDerivative<Arg> for ScaleNoise<Inner, InScale, OutScale> where
+	Arg: Mul<InScale>,
+	Inner: NoiseDerivative<Arg::Output>,
+	Inner::Value: Mul<OutScale>,
+	Inner::Derivative: Mul<InScale>,
+	<Inner::Derivative as Mul<InScale>>::Output: Mul<OutScale>,
+{
+	type Derivative = <<Inner::Derivative as Mul<InScale>>::Output as Mul<OutScale>>::Output;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let (value, derivative) = self.inner.noise_derivative(arg * self.scale_in);
+		(value * self.scale_out, derivative * self.scale_in * self.scale_out)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg, Inner, InScale: Copy, OutScale: Copy> NoiseBatch<Arg> for ScaleNoise<Inner, InScale, OutScale> where
+	Arg: Mul<InScale>,
+	Inner: NoiseBatch<Arg::Output>,
+	Inner::Value: Mul<OutScale>,
+{
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		self.inner.noise_batch(args.map(|arg| arg * self.scale_in)).map(|value| value * self.scale_out)
+	}
 }
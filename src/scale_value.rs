@@ -0,0 +1,75 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, NoiseDerivative}, math::Float, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
+
+use core::ops::Mul;
+
+/// A noise function which scales only the output values of a floating point noise function.
+///
+/// `noise(x) = inner.noise(x) * scale`
+///
+/// `scale` is converted to the inner noise function's value type, so this works for both `f64` and `f32` noise.
+#[derive(Copy, Clone)]
+pub struct ScaleValue<Inner> {
+	inner: Inner,
+	scale: f64,
+}
+
+noise_ops!([Inner,] ScaleValue<Inner>);
+
+impl<Inner: Noise> Noise for ScaleValue<Inner> where Inner::Value: Float {
+	type Value = Inner::Value;
+	type Unseeded = ScaleValue<Inner::Unseeded>;
+}
+
+impl<Inner> ScaleValue<Inner> {
+	pub fn new(inner: Inner, scale: f64) -> ScaleValue<Inner> {
+		ScaleValue { inner, scale }
+	}
+}
+
+impl<Inner: Seedable> Seedable for ScaleValue<Inner> {
+	type Seed = Inner::Seed;
+	type Seeded = ScaleValue<Inner::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		ScaleValue { inner: self.inner.seed(seed), scale: self.scale }
+	}
+}
+
+impl<Inner: Seeded> Seeded for ScaleValue<Inner> {
+	type Config = ScaleValue<Inner::Config>;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg>> NoiseDomain<Arg> for ScaleValue<Inner> where Inner::Value: Float {
+	fn noise(&self, arg: Arg) -> Self::Value {
+		self.inner.noise(arg) * Inner::Value::from_f64(self.scale)
+	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		let scale = Inner::Value::from_f64(self.scale);
+		self.inner.noise_points(points).map(move |value| value * scale)
+	}
+}
+
+impl<Arg, Inner: NoiseDerivative<Arg>> NoiseDerivative<Arg> for ScaleValue<Inner> where
+	Inner::Value: Float,
+	Inner::Derivative: Mul<Inner::Value>,
+{
+	type Derivative = <Inner::Derivative as Mul<Inner::Value>>::Output;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let scale = Inner::Value::from_f64(self.scale);
+		let (value, derivative) = self.inner.noise_derivative(arg);
+		(value * scale, derivative * scale)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg, Inner: NoiseBatch<Arg>> NoiseBatch<Arg> for ScaleValue<Inner> where Inner::Value: Float {
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		let scale = Inner::Value::from_f64(self.scale);
+		self.inner.noise_batch(args).map(|value| value * scale)
+	}
+}
@@ -1,31 +1,63 @@
-use super::{NoiseDomain, HashNoise, helpers::{SeedOnlyNoise, EmptyConfig}};
+use super::{NoiseDomain, HashNoise, ScaleValue, noise::NoiseDerivative, helpers::{SeedOnlyNoise, EmptyConfig}, math::{Float, floor, sqrt, powi, dot}, ops::noise_ops};
 
-use sized_matrix::{Vector, Dot};
+use sized_matrix::Vector;
 
-use higher_order_functions::{Map, Zip, Section};
+use higher_order_functions::{Init, Map, Zip};
+
+use num_traits::Zero;
+
+use core::{marker::PhantomData, ops::Mul};
 
 use lazy_static::lazy_static;
 
+#[cfg(feature = "simd")]
+use super::{noise::NoiseBatch, math::{SimdFloat, dot_lanes}};
+
 /// A simplex noise function loosely based on Stefan Gustavson's open source implementation.
+///
+/// This works in any number of dimensions from 1 up, e.g. 5D can be used for looping animations of 3D volumes.
+///
+/// `T` is the floating point type used for positions and values, i.e. `f64` (the default) or `f32`, or `i32` for fixed point
+/// (see [`Simplex::new_fixed`]).
 #[derive(Copy, Clone)]
-pub struct Simplex {
+pub struct Simplex<T = f64> {
 	inner: HashNoise,
+	_phantom: PhantomData<fn() -> T>,
+}
+
+noise_ops!(unscaled [T,] Simplex<T>);
+
+// `Simplex<i32>` is fixed point, so only the floating point versions get `* f64`.
+impl<T: Float> Mul<f64> for Simplex<T> {
+	type Output = ScaleValue<Self>;
+	
+	fn mul(self, rhs: f64) -> ScaleValue<Self> {
+		ScaleValue::new(self, rhs)
+	}
 }
 
-impl Simplex {
+impl<T: Float> Simplex<T> {
 	pub fn new() -> EmptyConfig<Self> {
 		EmptyConfig::new()
 	}
 }
 
-impl SeedOnlyNoise for Simplex {
+impl<T> Simplex<T> {
+	pub(crate) fn from_hash(inner: HashNoise) -> Self {
+		Self { inner, _phantom: PhantomData }
+	}
+	
+	pub(crate) fn hash(&self) -> &HashNoise {
+		&self.inner
+	}
+}
+
+impl<T: Float> SeedOnlyNoise for Simplex<T> {
 	type Seed = u64;
-	type Value = f64;
+	type Value = T;
 	
 	fn seed(seed: u64) -> Self {
-		Self {
-			inner: HashNoise::seed(seed)
-		}
+		Self::from_hash(HashNoise::seed(seed))
 	}
 }
 
@@ -33,14 +65,31 @@ impl SeedOnlyNoise for Simplex {
 
 fn f(n: u32) -> f64 {
 	let n = n as f64;
-	((n + 1.).sqrt() - 1.) / n
+	(sqrt(n + 1.) - 1.) / n
 }
 
 fn g(n: u32) -> f64 {
 	let n = n as f64;
-	(n + 1. - (n + 1.).sqrt()) / (n * (n + 1.))
+	(n + 1. - sqrt(n + 1.)) / (n * (n + 1.))
 }
 
+pub(crate) const GRAD3_INT: [[i8; 3]; 12] = [
+	[1, 1, 0], [-1, 1, 0], [1, -1, 0], [-1, -1, 0],
+	[1, 0, 1], [-1, 0, 1], [1, 0, -1], [-1, 0, -1],
+	[0, 1, 1], [0, -1, 1], [0, 1, -1], [0, -1, -1],
+];
+
+pub(crate) const GRAD4_INT: [[i8; 4]; 32] = [
+	[0, 1, 1, 1], [0, 1, 1, -1], [0, 1, -1, 1], [0, 1, -1, -1],
+	[0, -1, 1, 1], [0, -1, 1, -1], [0, -1, -1, 1], [0, -1, -1, -1],
+	[1, 0, 1, 1], [1, 0, 1, -1], [1, 0, -1, 1], [1, 0, -1, -1],
+	[-1, 0, 1, 1], [-1, 0, 1, -1], [-1, 0, -1, 1], [-1, 0, -1, -1],
+	[1, 1, 0, 1], [1, 1, 0, -1], [1, -1, 0, 1], [1, -1, 0, -1],
+	[-1, 1, 0, 1], [-1, 1, 0, -1], [-1, -1, 0, 1], [-1, -1, 0, -1],
+	[1, 1, 1, 0], [1, 1, -1, 0], [1, -1, 1, 0], [1, -1, -1, 0],
+	[-1, 1, 1, 0], [-1, 1, -1, 0], [-1, -1, 1, 0], [-1, -1, -1, 0],
+];
+
 lazy_static! {
 	static ref F2: f64 = f(2);
 	static ref G2: f64 = g(2);
@@ -49,163 +98,264 @@ lazy_static! {
 	static ref F4: f64 = f(4);
 	static ref G4: f64 = g(4);
 	
-	static ref GRAD3: [Vector<f64, 3>; 12] = [
-		[1., 1., 0.], [-1., 1., 0.], [1., -1., 0.], [-1., -1., 0.],
-		[1., 0., 1.], [-1., 0., 1.], [1., 0., -1.], [-1., 0., -1.],
-		[0., 1., 1.], [0., -1., 1.], [0., 1., -1.], [0., -1., -1.],
-	].map(Vector::vector);
-	
-	static ref GRAD4: [Vector<f64, 4>; 32] = [
-		[0., 1., 1., 1.], [0., 1., 1., -1.], [0., 1., -1., 1.], [0., 1., -1., -1.],
-		[0., -1., 1., 1.], [0., -1., 1., -1.], [0., -1., -1., 1.], [0., -1., -1., -1.],
-		[1., 0., 1., 1.], [1., 0., 1., -1.], [1., 0., -1., 1.], [1., 0., -1., -1.],
-		[-1., 0., 1., 1.], [-1., 0., 1., -1.], [-1., 0., -1., 1.], [-1., 0., -1., -1.],
-		[1., 1., 0., 1.], [1., 1., 0., -1.], [1., -1., 0., 1.], [1., -1., 0., -1.],
-		[-1., 1., 0., 1.], [-1., 1., 0., -1.], [-1., -1., 0., 1.], [-1., -1., 0., -1.],
-		[1., 1., 1., 0.], [1., 1., -1., 0.], [1., -1., 1., 0.], [1., -1., -1., 0.],
-		[-1., 1., 1., 0.], [-1., 1., -1., 0.], [-1., -1., 1., 0.], [-1., -1., -1., 0.],
-	].map(Vector::vector);
-}
-
-fn simplex_factor_2(inner: &HashNoise, base: Vector<i64, 2>, rel: Vector<f64, 2>, base_offset: Vector<i64, 2>, rel_offset: f64) -> f64 {
-	let base = base + base_offset;
-	let rel = rel.zip_with(base_offset, |r, o| r - o as f64 + rel_offset);
-	
-	let t = 0.5 - rel.dot(rel);
-	if t < 0. { 0.0 }
+	pub(crate) static ref GRAD3: [Vector<f64, 3>; 12] = GRAD3_INT.map(|grad| Vector::vector(grad.map(f64::from)));
+	
+	pub(crate) static ref GRAD4: [Vector<f64, 4>; 32] = GRAD4_INT.map(|grad| Vector::vector(grad.map(f64::from)));
+}
+
+fn simplex_factor<T: Float, const N: usize, Grad: FnOnce() -> Vector<T, N>>(r_squared: T, rel: Vector<T, N>, grad: Grad) -> T {
+	let t = r_squared - dot(rel, rel);
+	if t < T::zero() { T::zero() }
 	else {
-		let p = inner.noise(base) % 12;
-		t.powi(4) * rel.dot(GRAD3[p as usize].section(0))
+		powi(t, 4) * dot(rel, grad())
 	}
 }
 
-impl NoiseDomain<Vector<f64, 2>> for Simplex {
-	fn noise(&self, pos: Vector<f64, 2>) -> f64 {
-		let s = (pos[0] + pos[1]) * *F2;
-		let base = pos.map(|x| (x + s).floor());
-		
-		let t = (base[0] + base[1]) * *G2;
-		let rel = pos - base + Vector::vector([t, t]);
-		
-		let base = base.map(|x| x as i64);
-		
-		return 70.0 * (
-			simplex_factor_2(&self.inner, base, rel, Vector::vector([0, 0]), 0.) +
-			simplex_factor_2(&self.inner, base, rel,
-				if rel[0] >= rel[1] {
-					Vector::vector([1, 0])
-				} else {
-					Vector::vector([0, 1])
-				}, *G2) +
-			simplex_factor_2(&self.inner, base, rel, Vector::vector([1, 1]), 2. * *G2)
-		)
+fn simplex_factor_derivative<T: Float, const N: usize, Grad: FnOnce() -> Vector<T, N>>(r_squared: T, rel: Vector<T, N>, grad: Grad) -> (T, Vector<T, N>) {
+	let t = r_squared - dot(rel, rel);
+	if t < T::zero() { (T::zero(), Vector::zero()) }
+	else {
+		let grad = grad();
+		let g = dot(rel, grad);
+		let t3 = powi(t, 3);
+		(t3 * t * g, grad.map(|x| x * (t3 * t)) - rel.map(|x| x * (T::from_f64(8.) * t3 * g)))
 	}
 }
 
-fn simplex_factor_3(inner: &HashNoise, base: Vector<i64, 3>, rel: Vector<f64, 3>, base_offset: Vector<i64, 3>, rel_offset: f64) -> f64 {
-	let base = base + base_offset;
-	let rel = rel.zip_with(base_offset, |r, o| r - o as f64 + rel_offset);
+fn simplex_rel<T: Float, const N: usize>(rel: Vector<T, N>, base_offset: Vector<i64, N>, rel_offset: T) -> Vector<T, N> {
+	rel.zip_with(base_offset, |r, o| r - T::from_i64(o) + rel_offset)
+}
+
+fn simplex_sum<T: Float, const N: usize>(v: Vector<T, N>) -> T {
+	(0..N).map(|i| v[i]).reduce(|a, b| a + b).unwrap_or(T::zero())
+}
+
+fn simplex_skew_factors(n: usize) -> (f64, f64) {
+	match n {
+		2 => (*F2, *G2),
+		3 => (*F3, *G3),
+		4 => (*F4, *G4),
+		n => (f(n as u32), g(n as u32)),
+	}
+}
+
+fn simplex_skew<T: Float, const N: usize>(pos: Vector<T, N>) -> (Vector<i64, N>, Vector<T, N>, T) {
+	const { assert!(N > 0, "simplex noise needs at least one dimension") };
+	let (f, g) = simplex_skew_factors(N);
+	let g = T::from_f64(g);
 	
-	let t = 0.6 - rel.dot(rel);
-	if t < 0. { 0.0 }
-	else {
-		let p = inner.noise(base) % 12;
-		t.powi(4) * rel.dot(GRAD3[p as usize])
+	let s = simplex_sum(pos) * T::from_f64(f);
+	let base = pos.map(|x| floor(x + s));
+	
+	let t = simplex_sum(base) * g;
+	let rel = pos - base + Vector::init(|_: usize| t);
+	
+	(base.map(|x| x.to_i64()), rel, g)
+}
+
+/// The rank of each coordinate, from largest to smallest, with ties going to the lowest index.
+///
+/// The `k`th vertex of the simplex containing `rel` is offset by 1 along the axes with rank less than `k`.
+pub(crate) fn simplex_ranks<T: PartialOrd, const N: usize>(rel: Vector<T, N>) -> [usize; N] {
+	<[usize; N]>::init(|i| (0..N).filter(|&j| rel[j] > rel[i] || (rel[j] == rel[i] && j < i)).count())
+}
+
+pub(crate) fn simplex_offset<const N: usize>(ranks: [usize; N], k: usize) -> Vector<i64, N> {
+	Vector::init(|i: usize| (ranks[i] < k) as i64)
+}
+
+// 0.6 in 3D and 4D matches the original implementation, but 0.5 is needed to keep the noise continuous.
+fn simplex_r_squared<T: Float>(n: usize) -> T {
+	T::from_f64(if n == 3 || n == 4 { 0.6 } else { 0.5 })
+}
+
+// Scale factors bringing the output to approximately [-1, 1], found empirically above 4D.
+fn simplex_scale<T: Float>(n: usize) -> T {
+	T::from_f64(match n {
+		1 => 8.9,
+		2 => 70.0,
+		3 => 32.0,
+		4 => 27.0,
+		5 => 54.0,
+		6 => 48.0,
+		_ => 40.0,
+	})
+}
+
+fn simplex_gradient<T: Float, const N: usize>(h: u64) -> Vector<T, N> {
+	let grad = |grad: &[i8]| Vector::init(|i: usize| T::from_i64(i64::from(grad[i])));
+	match N {
+		1 => Vector::init(|_: usize| {
+			let g = T::from_i64(1 + (h & 7) as i64);
+			if h & 8 == 0 { g } else { -g }
+		}),
+		2 | 3 => grad(&GRAD3_INT[(h % 12) as usize]),
+		4 => grad(&GRAD4_INT[(h % 32) as usize]),
+		// The midpoints of the edges of a hypercube, as in 3D and 4D.
+		_ => {
+			let zero = (h % N as u64) as usize;
+			let signs = h / N as u64;
+			Vector::init(|i: usize| {
+				if i == zero { T::zero() }
+				else if (signs >> (i % 64)) & 1 == 0 { T::one() }
+				else { -T::one() }
+			})
+		},
 	}
 }
 
-impl NoiseDomain<Vector<f64, 3>> for Simplex {
-	fn noise(&self, pos: Vector<f64, 3>) -> f64 {
-		let s = (pos[0] + pos[1] + pos[2]) * *F3;
-		let base = pos.map(|x| (x + s).floor());
-		
-		let t = (base[0] + base[1] + base[2]) * *G3;
-		let rel = pos - base + Vector::vector([t, t, t]);
-		
-		let base = base.map(|x| x as i64);
-		
-		return 32.0 * (
-			simplex_factor_3(&self.inner, base, rel, Vector::vector([0, 0, 0]), 0.) +
-			simplex_factor_3(&self.inner, base, rel,
-				if rel[0] >= rel[1] && rel[0] >= rel[2] {
-					Vector::vector([1, 0, 0])
-				} else if rel[1] >= rel[0] && rel[1] >= rel[2] {
-					Vector::vector([0, 1, 0])
-				} else {
-					Vector::vector([0, 0, 1])
-				}, *G3) +
-			simplex_factor_3(&self.inner, base, rel,
-				if rel[0] >= rel[2] && rel[1] >= rel[2] {
-					Vector::vector([1, 1, 0])
-				} else if rel[0] >= rel[1] && rel[2] >= rel[1] {
-					Vector::vector([1, 0, 1])
-				} else {
-					Vector::vector([0, 1, 1])
-				}, 2. * *G3) +
-			simplex_factor_3(&self.inner, base, rel, Vector::vector([1, 1, 1]), 3. * *G3)
-		)
-	}
-}
-
-fn simplex_factor_4(inner: &HashNoise, base: Vector<i64, 4>, rel: Vector<f64, 4>, base_offset: Vector<i64, 4>, rel_offset: f64) -> f64 {
-	let base = base + base_offset;
-	let rel = rel.zip_with(base_offset, |r, o| r - o as f64 + rel_offset);
-	
-	let t = 0.6 - rel.dot(rel);
-	if t < 0. { 0.0 }
-	else {
-		let p = inner.noise(base) % 32;
-		t.powi(4) * rel.dot(GRAD4[p as usize])
+fn simplex_noise<T: Float, const N: usize, Hash: FnMut(Vector<i64, N>) -> u64>(pos: Vector<T, N>, mut hash: Hash) -> T {
+	let (base, rel, g) = simplex_skew(pos);
+	let ranks = simplex_ranks(rel);
+	
+	let mut value = T::zero();
+	for k in 0..=N {
+		let offset = simplex_offset(ranks, k);
+		value += simplex_factor(simplex_r_squared(N), simplex_rel(rel, offset, T::from_i64(k as i64) * g), || simplex_gradient(hash(base + offset)));
+	}
+	simplex_scale::<T>(N) * value
+}
+
+const HASH_CACHE_SIZE: usize = 64;
+
+// A direct-mapped cache of lattice hashes, since neighbouring points in a grid share most of their simplex vertices.
+struct HashCache<'a, const N: usize> {
+	inner: &'a HashNoise,
+	valid: u64,
+	vertices: [[i64; N]; HASH_CACHE_SIZE],
+	hashes: [u64; HASH_CACHE_SIZE],
+}
+
+impl<'a, const N: usize> HashCache<'a, N> {
+	fn new(inner: &'a HashNoise) -> Self {
+		Self { inner, valid: 0, vertices: [[0; N]; HASH_CACHE_SIZE], hashes: [0; HASH_CACHE_SIZE] }
+	}
+	
+	fn hash(&mut self, vertex: Vector<i64, N>) -> u64 {
+		let vertex = <[i64; N]>::init(|i| vertex[i]);
+		let mut slot = 0u64;
+		for x in vertex {
+			slot = (slot ^ x as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+		}
+		let slot = (slot >> 58) as usize;
+		if self.valid & (1 << slot) != 0 && self.vertices[slot] == vertex {
+			self.hashes[slot]
+		} else {
+			let h = self.inner.noise(Vector::vector(vertex));
+			self.valid |= 1 << slot;
+			self.vertices[slot] = vertex;
+			self.hashes[slot] = h;
+			h
+		}
 	}
 }
 
-impl NoiseDomain<Vector<f64, 4>> for Simplex {
-	fn noise(&self, pos: Vector<f64, 4>) -> f64 {
-		let s = (pos[0] + pos[1] + pos[2] + pos[3]) * *F4;
-		let base = pos.map(|x| (x + s).floor());
+impl<T: Float, const N: usize> NoiseDomain<Vector<T, N>> for Simplex<T> {
+	fn noise(&self, pos: Vector<T, N>) -> T {
+		simplex_noise(pos, |vertex| self.inner.noise(vertex))
+	}
+	
+	fn noise_points<Points: Iterator<Item = Vector<T, N>> + Clone>(&self, points: Points) -> impl Iterator<Item = T> {
+		let mut cache = HashCache::new(&self.inner);
+		points.map(move |pos| simplex_noise(pos, |vertex| cache.hash(vertex)))
+	}
+}
+
+// The same as `simplex_noise` at each point, but with the arithmetic done across SIMD lanes.
+#[cfg(feature = "simd")]
+fn simplex_batch<T: SimdFloat, const N: usize, const L: usize>(inner: &HashNoise, pos: [Vector<T, N>; L]) -> [T; L] {
+	const { assert!(N > 0, "simplex noise needs at least one dimension") };
+	let sum = |v: [T::Lanes<L>; N]| v.into_iter().reduce(|a, b| a + b).unwrap_or(T::splat(T::zero()));
+	
+	let (f, g) = simplex_skew_factors(N);
+	let g = T::from_f64(g);
+	
+	let pos = <[T::Lanes<L>; N]>::init(|i| T::from_array(pos.map(|p| p[i])));
+	let s = sum(pos) * T::splat(T::from_f64(f));
+	let base = pos.map(|x| T::floor_lanes(x + s));
+	
+	let t = sum(base) * T::splat(g);
+	let rel = <[T::Lanes<L>; N]>::init(|i| pos[i] - base[i] + t);
+	
+	let base = base.map(T::to_array);
+	let base = <[Vector<i64, N>; L]>::init(|lane| Vector::init(|i: usize| base[i][lane].to_i64()));
+	
+	// As in `simplex_ranks`, with ties going to the lowest index.
+	let ranks = <[T::Lanes<L>; N]>::init(|i| {
+		(0..N).filter(|&j| j != i).map(|j| T::one_where_greater(rel[j], rel[i], j < i)).fold(T::splat(T::zero()), |a, b| a + b)
+	});
+	
+	let r_squared = T::splat(simplex_r_squared(N));
+	let mut value = T::splat(T::zero());
+	for k in 0..=N {
+		let offset = ranks.map(|rank| T::one_where_greater(T::splat(T::from_i64(k as i64)), rank, false));
+		let rel_offset = T::splat(T::from_i64(k as i64) * g);
+		let rel = <[T::Lanes<L>; N]>::init(|i| rel[i] - offset[i] + rel_offset);
 		
-		let t = (base[0] + base[1] + base[2] + base[3]) * *G4;
-		let rel = pos - base + Vector::vector([t, t, t, t]);
+		let t = r_squared - dot_lanes::<T, N, L>(rel, rel);
+		let t_lanes = T::to_array(t);
+		let offset = offset.map(T::to_array);
+		let grads = <[Vector<T, N>; L]>::init(|lane| {
+			if t_lanes[lane] < T::zero() { Vector::zero() }
+			else { simplex_gradient(inner.noise(base[lane] + Vector::init(|i: usize| offset[i][lane].to_i64()))) }
+		});
+		let grad = <[T::Lanes<L>; N]>::init(|i| T::from_array(grads.map(|grad| grad[i])));
 		
-		let base = base.map(|x| x as i64);
+		let t2 = t * t;
+		value = value + T::zero_where_negative(t, t2 * t2 * dot_lanes::<T, N, L>(rel, grad));
+	}
+	T::to_array(T::splat(simplex_scale(N)) * value)
+}
+
+#[cfg(feature = "simd")]
+impl<const N: usize> NoiseBatch<Vector<f64, N>> for Simplex<f64> {
+	fn noise_batch<const L: usize>(&self, args: [Vector<f64, N>; L]) -> [f64; L] {
+		simplex_batch(&self.inner, args)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<const N: usize> NoiseBatch<Vector<f32, N>> for Simplex<f32> {
+	fn noise_batch<const L: usize>(&self, args: [Vector<f32, N>; L]) -> [f32; L] {
+		simplex_batch(&self.inner, args)
+	}
+}
+
+impl<T: Float, const N: usize> NoiseDerivative<Vector<T, N>> for Simplex<T> {
+	type Derivative = Vector<T, N>;
+	
+	fn noise_derivative(&self, pos: Vector<T, N>) -> (T, Vector<T, N>) {
+		let (base, rel, g) = simplex_skew(pos);
+		let ranks = simplex_ranks(rel);
 		
-		return 27.0 * (
-			simplex_factor_4(&self.inner, base, rel, Vector::vector([0, 0, 0, 0]), 0.) +
-			simplex_factor_4(&self.inner, base, rel,
-				if rel[0] >= rel[1] && rel[0] >= rel[2] && rel[0] >= rel[3] {
-					Vector::vector([1, 0, 0, 0])
-				} else if rel[1] >= rel[0] && rel[1] >= rel[2] && rel[1] >= rel[3] {
-					Vector::vector([0, 1, 0, 0])
-				} else if rel[2] >= rel[0] && rel[2] >= rel[1] && rel[2] >= rel[3] {
-					Vector::vector([0, 0, 1, 0])
-				} else {
-					Vector::vector([0, 0, 0, 1])
-				}, *G4) +
-			simplex_factor_4(&self.inner, base, rel,
-				if rel[0] >= rel[2] && rel[0] >= rel[3] && rel[1] >= rel[2] && rel[1] >= rel[3] {
-					Vector::vector([1, 1, 0, 0])
-				} else if rel[0] >= rel[1] && rel[0] >= rel[3] && rel[2] >= rel[1] && rel[2] >= rel[3] {
-					Vector::vector([1, 0, 1, 0])
-				} else if rel[0] >= rel[2] && rel[0] >= rel[1] && rel[3] >= rel[2] && rel[3] >= rel[1] {
-					Vector::vector([1, 0, 0, 1])
-				} else if rel[2] >= rel[0] && rel[2] >= rel[3] && rel[1] >= rel[0] && rel[1] >= rel[3] {
-					Vector::vector([0, 1, 1, 0])
-				} else if rel[3] >= rel[2] && rel[3] >= rel[0] && rel[1] >= rel[2] && rel[1] >= rel[0] {
-					Vector::vector([0, 1, 0, 1])
-				} else {
-					Vector::vector([0, 0, 1, 1])
-				}, 2. * *G4) +
-			simplex_factor_4(&self.inner, base, rel,
-				if rel[0] >= rel[3] && rel[1] >= rel[3] && rel[2] >= rel[3] {
-					Vector::vector([1, 1, 1, 0])
-				} else if rel[0] >= rel[2] && rel[1] >= rel[2] && rel[3] >= rel[2] {
-					Vector::vector([1, 1, 0, 1])
-				} else if rel[0] >= rel[1] && rel[2] >= rel[1] && rel[3] >= rel[1] {
-					Vector::vector([1, 0, 1, 1])
-				} else {
-					Vector::vector([0, 1, 1, 1])
-				}, 3. * *G4) +
-			simplex_factor_4(&self.inner, base, rel, Vector::vector([1, 1, 1, 1]), 4. * *G4)
-		)
+		let mut value = T::zero();
+		let mut derivative = Vector::<T, N>::zero();
+		for k in 0..=N {
+			let offset = simplex_offset(ranks, k);
+			let (v, d) = simplex_factor_derivative(simplex_r_squared(N), simplex_rel(rel, offset, T::from_i64(k as i64) * g), || simplex_gradient(self.inner.noise(base + offset)));
+			value += v;
+			derivative += d;
+		}
+		let scale = simplex_scale::<T>(N);
+		(scale * value, derivative.map(|x| x * scale))
+	}
+}
+
+impl<T: Float> NoiseDomain<T> for Simplex<T> {
+	fn noise(&self, x: T) -> T {
+		self.noise(Vector::vector([x]))
+	}
+	
+	fn noise_points<Points: Iterator<Item = T> + Clone>(&self, points: Points) -> impl Iterator<Item = T> {
+		NoiseDomain::<Vector<T, 1>>::noise_points(self, points.map(|x| Vector::vector([x])))
+	}
+}
+
+impl<T: Float> NoiseDerivative<T> for Simplex<T> {
+	type Derivative = T;
+	
+	fn noise_derivative(&self, x: T) -> (T, T) {
+		let (value, derivative) = self.noise_derivative(Vector::vector([x]));
+		(value, derivative[0])
 	}
 }
@@ -0,0 +1,81 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
+
+#[cfg(feature = "simd")]
+use higher_order_functions::Zip;
+
+use core::ops::Sub;
+
+/// A noise function which subtracts one noise function from another.
+///
+/// `noise(x) = inner1.noise(x) - inner2.noise(x)`
+#[derive(Copy, Clone)]
+pub struct SubNoise<Inner1, Inner2> {
+	inner1: Inner1,
+	inner2: Inner2,
+}
+
+noise_ops!([Inner1, Inner2,] SubNoise<Inner1, Inner2>);
+
+impl<Inner1: Noise, Inner2: Noise> Noise for SubNoise<Inner1, Inner2> where Inner1::Value: Sub<Inner2::Value> {
+	type Value = <Inner1::Value as Sub<Inner2::Value>>::Output;
+	type Unseeded = SubNoise<Inner1::Unseeded, Inner2::Unseeded>;
+}
+
+impl<Inner1, Inner2> SubNoise<Inner1, Inner2> {
+	pub fn new(inner1: Inner1, inner2: Inner2) -> SubNoise<Inner1, Inner2> {
+		SubNoise { inner1, inner2 }
+	}
+}
+
+impl<Seed: SplitSeed, Inner1: Seedable<Seed = Seed>, Inner2: Seedable<Seed = Seed>> Seedable for SubNoise<Inner1, Inner2> {
+	type Seed = Seed;
+	type Seeded = SubNoise<Inner1::Seeded, Inner2::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		SubNoise { inner1: self.inner1.seed(seed.split(0)), inner2: self.inner2.seed(seed.split(1)) }
+	}
+}
+
+impl<Seed: SplitSeed, Inner1: Seeded, Inner2: Seeded> Seeded for SubNoise<Inner1, Inner2> where
+	Inner1::Config: Seedable<Seed = Seed>,
+	Inner2::Config: Seedable<Seed = Seed>,
+{
+	type Config = SubNoise<Inner1::Config, Inner2::Config>;
+}
+
+impl<Arg: Copy, Inner1: NoiseDomain<Arg>, Inner2: NoiseDomain<Arg>> NoiseDomain<Arg> for SubNoise<Inner1, Inner2> where
+	Inner1::Value: Sub<Inner2::Value>,
+{
+	fn noise(&self, arg: Arg) -> Self::Value {
+		self.inner1.noise(arg) - self.inner2.noise(arg)
+	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		self.inner1.noise_points(points.clone()).zip(self.inner2.noise_points(points)).map(|(value1, value2)| value1 - value2)
+	}
+}
+
+impl<Arg: Copy, Inner1: NoiseDerivative<Arg>, Inner2: NoiseDerivative<Arg>> NoiseDerivative<Arg> for SubNoise<Inner1, Inner2> where
+	Inner1::Value: Sub<Inner2::Value>,
+	Inner1::Derivative: Sub<Inner2::Derivative>,
+{
+	type Derivative = <Inner1::Derivative as Sub<Inner2::Derivative>>::Output;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let (value1, derivative1) = self.inner1.noise_derivative(arg);
+		let (value2, derivative2) = self.inner2.noise_derivative(arg);
+		(value1 - value2, derivative1 - derivative2)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg: Copy, Inner1: NoiseBatch<Arg>, Inner2: NoiseBatch<Arg>> NoiseBatch<Arg> for SubNoise<Inner1, Inner2> where
+	Inner1::Value: Sub<Inner2::Value>,
+{
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		self.inner1.noise_batch(args).zip_with(self.inner2.noise_batch(args), |value1, value2| value1 - value2)
+	}
+}
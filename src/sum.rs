@@ -1,15 +1,22 @@
-use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}};
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed, NoiseDerivative}, ops::noise_ops};
+
+#[cfg(feature = "simd")]
+use super::noise::NoiseBatch;
 
 use core::ops::AddAssign;
 use num_traits::Zero;
 use higher_order_functions::{Zip, Init};
 
 /// A noise function formed by summing an array of other noise functions.
+///
+/// The values are always summed in order, starting from zero.
 #[derive(Copy, Clone)]
 pub struct SumNoise<Inner, const N: usize> {
 	inners: [Inner; N],
 }
 
+noise_ops!([Inner, const N: usize,] SumNoise<Inner, N>);
+
 impl<Inner: Noise, const N: usize> Noise for SumNoise<Inner, N> where Inner::Value: Zero + AddAssign {
 	type Value = Inner::Value;
 	type Unseeded = SumNoise<Inner::Unseeded, N>;
@@ -42,4 +49,46 @@ impl<Arg: Copy, Inner: NoiseDomain<Arg>, const N: usize> NoiseDomain<Arg> for Su
 		}
 		sum
 	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Self::Value> {
+		let mut inners = self.inners.each_ref().map(|inner| inner.noise_points(points.clone()));
+		points.map(move |_| {
+			let mut sum = Inner::Value::zero();
+			for values in &mut inners {
+				sum += values.next().expect("noise_points gave too few values");
+			}
+			sum
+		})
+	}
+}
+
+impl<Arg: Copy, Inner: NoiseDerivative<Arg>, const N: usize> NoiseDerivative<Arg> for SumNoise<Inner, N> where
+	Inner::Value: Zero + AddAssign,
+	Inner::Derivative: Zero + AddAssign,
+{
+	type Derivative = Inner::Derivative;
+	
+	fn noise_derivative(&self, arg: Arg) -> (Self::Value, Self::Derivative) {
+		let mut sum = Inner::Value::zero();
+		let mut derivative_sum = Inner::Derivative::zero();
+		for i in 0..N {
+			let (value, derivative) = self.inners[i].noise_derivative(arg);
+			sum += value;
+			derivative_sum += derivative;
+		}
+		(sum, derivative_sum)
+	}
+}
+
+#[cfg(feature = "simd")]
+impl<Arg: Copy, Inner: NoiseBatch<Arg>, const N: usize> NoiseBatch<Arg> for SumNoise<Inner, N> where Inner::Value: Zero + AddAssign {
+	fn noise_batch<const L: usize>(&self, args: [Arg; L]) -> [Self::Value; L] {
+		let mut sums = <[Inner::Value; L]>::init(|_| Inner::Value::zero());
+		for i in 0..N {
+			for (sum, value) in sums.iter_mut().zip(self.inners[i].noise_batch(args)) {
+				*sum += value;
+			}
+		}
+		sums
+	}
 }
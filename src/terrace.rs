@@ -0,0 +1,67 @@
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, math::Float, ops::noise_ops};
+
+use core::mem::swap;
+
+/// A noise function which maps the values of another noise function onto a series of terraces.
+///
+/// Between each pair of adjacent control points the value is eased quadratically from the lower point to the upper one,
+/// so the slope is flat at the start of each terrace and steepest just before the next. With `invert` the curve is
+/// flipped, giving sharp cliffs that flatten out towards the top of each step instead.
+///
+/// `points`: The heights of the terraces, in any order. Values outside their range are clamped to the lowest or highest.
+///
+/// `invert`: Whether to invert the shape of each terrace.
+#[derive(Copy, Clone)]
+pub struct Terrace<Inner, const N: usize, T = f64> {
+	inner: Inner,
+	points: [T; N],
+	invert: bool,
+}
+
+noise_ops!([Inner, const N: usize, T,] Terrace<Inner, N, T>);
+
+impl<Inner: Noise<Value = T>, const N: usize, T: Float> Noise for Terrace<Inner, N, T> {
+	type Value = T;
+	type Unseeded = Terrace<Inner::Unseeded, N, T>;
+}
+
+impl<Inner, const N: usize, T: Float> Terrace<Inner, N, T> {
+	/// Panics if there are fewer than two points, or any point is NaN.
+	pub fn new(inner: Inner, mut points: [T; N], invert: bool) -> Terrace<Inner, N, T> {
+		assert!(N >= 2, "a terrace needs at least two control points");
+		points.sort_unstable_by(|a, b| a.partial_cmp(b).expect("control points must not be NaN"));
+		Terrace { inner, points, invert }
+	}
+}
+
+impl<Inner: Seedable, const N: usize, T> Seedable for Terrace<Inner, N, T> {
+	type Seed = Inner::Seed;
+	type Seeded = Terrace<Inner::Seeded, N, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Terrace { inner: self.inner.seed(seed), points: self.points, invert: self.invert }
+	}
+}
+
+impl<Inner: Seeded, const N: usize, T> Seeded for Terrace<Inner, N, T> {
+	type Config = Terrace<Inner::Config, N, T>;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg, Value = T>, const N: usize, T: Float> NoiseDomain<Arg> for Terrace<Inner, N, T> {
+	fn noise(&self, arg: Arg) -> T {
+		let value = self.inner.noise(arg);
+		let upper = match self.points.iter().position(|&point| value < point) {
+			Some(0) => return self.points[0],
+			Some(upper) => upper,
+			None => return self.points[N - 1],
+		};
+		
+		let (mut low, mut high) = (self.points[upper - 1], self.points[upper]);
+		let mut t = (value - low) / (high - low);
+		if self.invert {
+			t = T::one() - t;
+			swap(&mut low, &mut high);
+		}
+		low + (high - low) * t * t
+	}
+}
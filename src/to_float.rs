@@ -1,44 +1,58 @@
-use super::{Noise, NoiseDomain, Seedable, noise::Seeded};
+use super::{Noise, NoiseDomain, Seedable, noise::Seeded, math::Float, ops::noise_ops};
+
+use core::marker::PhantomData;
 
 use num_traits::{Unsigned, PrimInt, AsPrimitive};
 
 /// Convert random uint values to floating point numbers in the range [0, 1).
+///
+/// `T` is the floating point type of the values, i.e. `f64` (the default) or `f32`.
 #[derive(Copy, Clone)]
-pub struct ToFloat<Inner> {
+pub struct ToFloat<Inner, T = f64> {
 	inner: Inner,
+	_phantom: PhantomData<fn() -> T>,
 }
 
-impl<Inner: Noise> Noise for ToFloat<Inner> where Inner::Value: Unsigned + PrimInt + AsPrimitive<u64> {
-	type Value = f64;
-	type Unseeded = ToFloat<Inner::Unseeded>;
+noise_ops!([Inner, T,] ToFloat<Inner, T>);
+
+impl<Inner: Noise, T: Float> Noise for ToFloat<Inner, T> where Inner::Value: Unsigned + PrimInt + AsPrimitive<u64> {
+	type Value = T;
+	type Unseeded = ToFloat<Inner::Unseeded, T>;
 }
 
 impl<Inner> ToFloat<Inner> {
 	pub fn new(inner: Inner) -> ToFloat<Inner> {
-		ToFloat { inner }
+		ToFloat { inner, _phantom: PhantomData }
+	}
+}
+
+impl<Inner> ToFloat<Inner, f32> {
+	/// Like `ToFloat::new`, but producing `f32`s.
+	pub fn new_f32(inner: Inner) -> ToFloat<Inner, f32> {
+		ToFloat { inner, _phantom: PhantomData }
 	}
 }
 
-impl<Inner: Seedable> Seedable for ToFloat<Inner> {
+impl<Inner: Seedable, T> Seedable for ToFloat<Inner, T> {
 	type Seed = Inner::Seed;
-	type Seeded = ToFloat<Inner::Seeded>;
+	type Seeded = ToFloat<Inner::Seeded, T>;
 	
 	fn seed(self, seed: Self::Seed) -> Self::Seeded {
-		ToFloat { inner: self.inner.seed(seed) }
+		ToFloat { inner: self.inner.seed(seed), _phantom: PhantomData }
 	}
 }
 
-impl<Inner: Seeded> Seeded for ToFloat<Inner> {
-	type Config = ToFloat<Inner::Config>;
+impl<Inner: Seeded, T> Seeded for ToFloat<Inner, T> {
+	type Config = ToFloat<Inner::Config, T>;
 }
 
-impl<Arg, Inner: NoiseDomain<Arg>> NoiseDomain<Arg> for ToFloat<Inner> where Inner::Value: Unsigned + PrimInt + AsPrimitive<u64> {
+impl<Arg, Inner: NoiseDomain<Arg>, T: Float> NoiseDomain<Arg> for ToFloat<Inner, T> where Inner::Value: Unsigned + PrimInt + AsPrimitive<u64> {
 	fn noise(&self, arg: Arg) -> Self::Value {
-		bits_to_f64(self.inner.noise(arg))
+		T::from_random_bits(self.inner.noise(arg))
 	}
 }
 
-fn bits_to_f64<T: Unsigned + PrimInt + AsPrimitive<u64>>(value: T) -> f64 {
+pub(crate) fn bits_to_f64<T: Unsigned + PrimInt + AsPrimitive<u64>>(value: T) -> f64 {
 	if value.is_zero() { 0.0 }
 	else {
 		let shift = value.leading_zeros();
@@ -47,3 +61,13 @@ fn bits_to_f64<T: Unsigned + PrimInt + AsPrimitive<u64>>(value: T) -> f64 {
 		f64::from_bits((u64::from(exponent) << 52) | fraction)
 	}
 }
+
+pub(crate) fn bits_to_f32<T: Unsigned + PrimInt + AsPrimitive<u64>>(value: T) -> f32 {
+	let shift = value.leading_zeros();
+	if value.is_zero() || shift >= 126 { 0.0 }
+	else {
+		let exponent = 126 - shift;
+		let fraction = value.unsigned_shl(shift).unsigned_shl(1).swap_bytes().as_().swap_bytes().unsigned_shr(41);
+		f32::from_bits((exponent << 23) | fraction as u32)
+	}
+}
@@ -0,0 +1,132 @@
+use super::{Noise, NoiseDomain, Seedable, HashNoise, ToFloat, noise::Seeded, helpers::EmptyConfig, math::{Float, floor}, fixed::{ONE, mul}, ops::noise_ops};
+
+use sized_matrix::Vector;
+
+use higher_order_functions::{Init, Map};
+
+/// The curve used to interpolate between lattice points in [`ValueNoise`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Interpolation {
+	/// Linear interpolation, `t`.
+	Linear,
+	/// Cubic Hermite interpolation, `3t^2 - 2t^3`.
+	Cubic,
+	/// Quintic smoothstep interpolation, `6t^5 - 15t^4 + 10t^3`.
+	Quintic,
+}
+
+impl Interpolation {
+	fn curve<T: Float>(self, t: T) -> T {
+		match self {
+			Interpolation::Linear => t,
+			Interpolation::Cubic => t * t * (T::from_f64(3.) - T::from_f64(2.) * t),
+			Interpolation::Quintic => t * t * t * (t * (t * T::from_f64(6.) - T::from_f64(15.)) + T::from_f64(10.)),
+		}
+	}
+	
+	pub(crate) fn curve_fixed(self, t: i64) -> i64 {
+		match self {
+			Interpolation::Linear => t,
+			Interpolation::Cubic => mul(mul(t, t), 3 * ONE - 2 * t),
+			Interpolation::Quintic => mul(mul(mul(t, t), t), mul(t, t * 6 - 15 * ONE) + 10 * ONE),
+		}
+	}
+}
+
+/// A value noise function, interpolating between pseudorandom values at each integer lattice point.
+///
+/// The values are in the range `[-1, 1)`.
+///
+/// A good default is `ValueNoise::new(Interpolation::Quintic)`.
+///
+/// The floating point type of the positions and values is the type of the lattice values, i.e. `f64` by default, or `f32` with
+/// [`ValueNoise::new_f32`].
+#[derive(Copy, Clone)]
+pub struct ValueNoise<Inner> {
+	inner: Inner,
+	interpolation: Interpolation,
+}
+
+noise_ops!([Inner,] ValueNoise<Inner>);
+
+impl ValueNoise<ToFloat<EmptyConfig<HashNoise>>> {
+	pub fn new(interpolation: Interpolation) -> Self {
+		ValueNoise { inner: ToFloat::new(HashNoise::new()), interpolation }
+	}
+}
+
+impl ValueNoise<ToFloat<EmptyConfig<HashNoise>, f32>> {
+	/// Like `ValueNoise::new`, but evaluated in `f32`.
+	pub fn new_f32(interpolation: Interpolation) -> Self {
+		ValueNoise { inner: ToFloat::new_f32(HashNoise::new()), interpolation }
+	}
+}
+
+impl<Inner: Noise> Noise for ValueNoise<Inner> where Inner::Value: Float {
+	type Value = Inner::Value;
+	type Unseeded = ValueNoise<Inner::Unseeded>;
+}
+
+impl<Inner: Seedable> Seedable for ValueNoise<Inner> {
+	type Seed = Inner::Seed;
+	type Seeded = ValueNoise<Inner::Seeded>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		ValueNoise { inner: self.inner.seed(seed), interpolation: self.interpolation }
+	}
+}
+
+impl<Inner: Seeded> Seeded for ValueNoise<Inner> {
+	type Config = ValueNoise<Inner::Config>;
+}
+
+impl<Inner> ValueNoise<Inner> {
+	fn value<T: Float, const N: usize>(&self, pos: Vector<T, N>) -> T where Inner: NoiseDomain<Vector<i64, N>, Value = T> {
+		let base = pos.map(floor);
+		let weights = (pos - base).map(|t| self.interpolation.curve(t));
+		let base = base.map(T::to_i64);
+		
+		let mut sum = T::zero();
+		for corner in 0..(1usize << N) {
+			let offset = Vector::<i64, N>::init(|i: usize| ((corner >> i) & 1) as i64);
+			
+			let mut weight = T::one();
+			for i in 0..N {
+				weight = weight * if offset[i] == 1 { weights[i] } else { T::one() - weights[i] };
+			}
+			
+			sum += weight * self.inner.noise(base + offset);
+		}
+		T::from_f64(2.) * sum - T::one()
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 1>, Value = T>, T: Float> NoiseDomain<Vector<T, 1>> for ValueNoise<Inner> {
+	fn noise(&self, pos: Vector<T, 1>) -> T {
+		self.value(pos)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 1>, Value = T>, T: Float> NoiseDomain<T> for ValueNoise<Inner> {
+	fn noise(&self, x: T) -> T {
+		self.value(Vector::vector([x]))
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 2>, Value = T>, T: Float> NoiseDomain<Vector<T, 2>> for ValueNoise<Inner> {
+	fn noise(&self, pos: Vector<T, 2>) -> T {
+		self.value(pos)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 3>, Value = T>, T: Float> NoiseDomain<Vector<T, 3>> for ValueNoise<Inner> {
+	fn noise(&self, pos: Vector<T, 3>) -> T {
+		self.value(pos)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 4>, Value = T>, T: Float> NoiseDomain<Vector<T, 4>> for ValueNoise<Inner> {
+	fn noise(&self, pos: Vector<T, 4>) -> T {
+		self.value(pos)
+	}
+}
@@ -0,0 +1,56 @@
+use super::{Noise, NoiseDomain, Seedable, noise::{Seeded, SplitSeed}, math::Float, ops::noise_ops};
+
+use core::ops::{Add, Mul};
+
+/// A noise function which distorts the input of one noise function using another (domain warping).
+///
+/// `noise(x) = inner.noise(x + offset.noise(x) * strength)`
+///
+/// `offset` must produce values of the same shape as `x`, e.g. a [`CurlNoise`](crate::CurlNoise).
+///
+/// `T` is the floating point type of `strength`, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct Warp<Inner, Offset, T: Copy = f64> {
+	inner: Inner,
+	offset: Offset,
+	strength: T,
+}
+
+noise_ops!([Inner, Offset, T: Copy,] Warp<Inner, Offset, T>);
+
+impl<Inner: Noise, Offset: Noise, T: Copy> Noise for Warp<Inner, Offset, T> {
+	type Value = Inner::Value;
+	type Unseeded = Warp<Inner::Unseeded, Offset::Unseeded, T>;
+}
+
+impl<Inner, Offset, T: Float> Warp<Inner, Offset, T> {
+	pub fn new(inner: Inner, offset: Offset, strength: T) -> Warp<Inner, Offset, T> {
+		Warp { inner, offset, strength }
+	}
+}
+
+impl<Seed: SplitSeed, Inner: Seedable<Seed = Seed>, Offset: Seedable<Seed = Seed>, T: Copy> Seedable for Warp<Inner, Offset, T> {
+	type Seed = Seed;
+	type Seeded = Warp<Inner::Seeded, Offset::Seeded, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Warp { inner: self.inner.seed(seed.split(0)), offset: self.offset.seed(seed.split(1)), strength: self.strength }
+	}
+}
+
+impl<Seed: SplitSeed, Inner: Seeded, Offset: Seeded, T: Copy> Seeded for Warp<Inner, Offset, T> where
+	Inner::Config: Seedable<Seed = Seed>,
+	Offset::Config: Seedable<Seed = Seed>,
+{
+	type Config = Warp<Inner::Config, Offset::Config, T>;
+}
+
+impl<Arg: Copy, Inner, Offset: NoiseDomain<Arg>, T: Copy> NoiseDomain<Arg> for Warp<Inner, Offset, T> where
+	Offset::Value: Mul<T>,
+	Arg: Add<<Offset::Value as Mul<T>>::Output>,
+	Inner: NoiseDomain<Arg::Output>,
+{
+	fn noise(&self, arg: Arg) -> Self::Value {
+		self.inner.noise(arg + self.offset.noise(arg) * self.strength)
+	}
+}
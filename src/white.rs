@@ -1,4 +1,4 @@
-use super::{NoiseDomain, perm_table::PermTable, helpers::{EmptyConfig, SeedOnlyNoise}};
+use super::{NoiseDomain, perm_table::PermTable, helpers::{EmptyConfig, SeedOnlyNoise}, ops::noise_ops};
 
 use sized_matrix::Vector;
 
@@ -8,6 +8,8 @@ pub struct WhiteNoise {
 	perm_table: PermTable,
 }
 
+noise_ops!(unscaled [] WhiteNoise);
+
 impl WhiteNoise {
 	pub fn new() -> EmptyConfig<Self> {
 		EmptyConfig::new()
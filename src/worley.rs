@@ -0,0 +1,141 @@
+use super::{Noise, NoiseDomain, Seedable, HashNoise, noise::{Seeded, SplitSeed}, helpers::EmptyConfig, math::{Float, floor, sqrt}, ops::noise_ops};
+
+use sized_matrix::Vector;
+
+use higher_order_functions::{Init, Map};
+
+/// The distance metric used to find the closest feature points in [`Worley`] noise.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum WorleyDistance {
+	/// Straight line distance, `sqrt(dx^2 + dy^2 + ...)`.
+	Euclidean,
+	/// Taxicab distance, `|dx| + |dy| + ...`.
+	Manhattan,
+	/// Chessboard distance, `max(|dx|, |dy|, ...)`.
+	Chebyshev,
+}
+
+/// The value returned by [`Worley`] noise.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum WorleyReturn {
+	/// The distance to the closest feature point.
+	F1,
+	/// The distance to the second closest feature point.
+	F2,
+	/// The difference between the distances to the second closest and closest feature points.
+	F2MinusF1,
+	/// A pseudorandom value in the range `[0, 1)` identifying the cell owning the closest feature point.
+	CellValue,
+}
+
+/// A Worley (cellular) noise function.
+///
+/// Each integer lattice cell contains a single feature point, placed using a hash of the cell's coordinates.
+///
+/// `jitter`: How far the feature points may move from the centre of their cell, from `0.0` (a regular grid) to `1.0` (anywhere in the cell).
+///
+/// A good default is `Worley::new(WorleyDistance::Euclidean, WorleyReturn::F1, 1.0)`.
+///
+/// `T` is the floating point type used for positions and values, i.e. `f64` (the default) or `f32`.
+#[derive(Copy, Clone)]
+pub struct Worley<Inner, T = f64> {
+	inner: Inner,
+	distance: WorleyDistance,
+	return_type: WorleyReturn,
+	jitter: T,
+}
+
+noise_ops!([Inner, T,] Worley<Inner, T>);
+
+impl<T: Float> Worley<EmptyConfig<HashNoise>, T> {
+	pub fn new(distance: WorleyDistance, return_type: WorleyReturn, jitter: T) -> Self {
+		Worley { inner: HashNoise::new(), distance, return_type, jitter }
+	}
+}
+
+impl<Inner: Noise, T: Float> Noise for Worley<Inner, T> {
+	type Value = T;
+	type Unseeded = Worley<Inner::Unseeded, T>;
+}
+
+impl<Inner: Seedable, T> Seedable for Worley<Inner, T> {
+	type Seed = Inner::Seed;
+	type Seeded = Worley<Inner::Seeded, T>;
+	
+	fn seed(self, seed: Self::Seed) -> Self::Seeded {
+		Worley { inner: self.inner.seed(seed), distance: self.distance, return_type: self.return_type, jitter: self.jitter }
+	}
+}
+
+impl<Inner: Seeded, T> Seeded for Worley<Inner, T> {
+	type Config = Worley<Inner::Config, T>;
+}
+
+impl<Inner, T: Float> Worley<Inner, T> {
+	fn distance<const N: usize>(&self, delta: Vector<T, N>) -> T {
+		let mut acc = T::zero();
+		for i in 0..N {
+			acc = match self.distance {
+				WorleyDistance::Euclidean => acc + delta[i] * delta[i],
+				WorleyDistance::Manhattan => acc + delta[i].abs(),
+				WorleyDistance::Chebyshev => if delta[i].abs() > acc { delta[i].abs() } else { acc },
+			};
+		}
+		if self.distance == WorleyDistance::Euclidean { sqrt(acc) } else { acc }
+	}
+	
+	fn worley<const N: usize>(&self, pos: Vector<T, N>) -> T where Inner: NoiseDomain<Vector<i64, N>, Value = u64> {
+		let base = pos.map(floor);
+		let rel = pos - base;
+		let base = base.map(T::to_i64);
+		
+		let mut f1 = T::from_f64(f64::INFINITY);
+		let mut f2 = T::from_f64(f64::INFINITY);
+		let mut f1_hash = 0;
+		
+		for neighbour in 0..3usize.pow(N as u32) {
+			let offset = Vector::<i64, N>::init(|i: usize| (neighbour / 3usize.pow(i as u32) % 3) as i64 - 1);
+			
+			let hash = self.inner.noise(base + offset);
+			let feature = Vector::<T, N>::init(|i: usize| {
+				let r = T::from_i64(((hash >> (16 * i)) & 0xFFFF) as i64) / T::from_f64(65536.);
+				T::from_i64(offset[i]) + T::from_f64(0.5) + self.jitter * (r - T::from_f64(0.5))
+			});
+			
+			let d = self.distance(feature - rel);
+			if d < f1 {
+				f2 = f1;
+				f1 = d;
+				f1_hash = hash;
+			} else if d < f2 {
+				f2 = d;
+			}
+		}
+		
+		match self.return_type {
+			WorleyReturn::F1 => f1,
+			WorleyReturn::F2 => f2,
+			WorleyReturn::F2MinusF1 => f2 - f1,
+			// The hash bits are all used to place the feature point, so rehash to keep the value independent of its position
+			WorleyReturn::CellValue => T::from_random_bits(f1_hash.split(0)),
+		}
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 2>, Value = u64>, T: Float> NoiseDomain<Vector<T, 2>> for Worley<Inner, T> {
+	fn noise(&self, pos: Vector<T, 2>) -> T {
+		self.worley(pos)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 3>, Value = u64>, T: Float> NoiseDomain<Vector<T, 3>> for Worley<Inner, T> {
+	fn noise(&self, pos: Vector<T, 3>) -> T {
+		self.worley(pos)
+	}
+}
+
+impl<Inner: NoiseDomain<Vector<i64, 4>, Value = u64>, T: Float> NoiseDomain<Vector<T, 4>> for Worley<Inner, T> {
+	fn noise(&self, pos: Vector<T, 4>) -> T {
+		self.worley(pos)
+	}
+}
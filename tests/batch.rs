@@ -0,0 +1,53 @@
+//! Batched evaluation must give exactly the same values as evaluating each point separately.
+
+#![cfg(feature = "simd")]
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, NoiseBatch, Simplex, Octaves, ScaleNoise, AddNoise, MinNoise, MaxNoise, LerpNoise};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+fn lanes<const N: usize, const L: usize>() -> [Vector<f64, N>; L] {
+	let mut points = points::<N>(L);
+	core::array::from_fn(|_| points.next().unwrap())
+}
+
+fn check_batch<const N: usize, const L: usize>(noise: impl NoiseBatch<Vector<f64, N>, Value = f64>) {
+	let points = lanes::<N, L>();
+	for (point, value) in points.into_iter().zip(noise.noise_batch(points)) {
+		assert_eq!(value.to_bits(), noise.noise(point).to_bits(), "at {:?}", point);
+	}
+}
+
+#[test]
+fn simplex_batch() {
+	let simplex = Simplex::new().seed(SEED);
+	check_batch::<1, 4>(simplex);
+	check_batch::<2, 4>(simplex);
+	check_batch::<3, 8>(simplex);
+	check_batch::<4, 8>(simplex);
+	check_batch::<5, 4>(simplex);
+}
+
+#[test]
+fn simplex_f32_batch() {
+	let simplex = Simplex::<f32>::new().seed(SEED);
+	let points = lanes::<3, 8>().map(|point| Vector::vector([point[0] as f32, point[1] as f32, point[2] as f32]));
+	for (point, value) in points.into_iter().zip(simplex.noise_batch(points)) {
+		assert_eq!(value.to_bits(), simplex.noise(point).to_bits(), "at {:?}", point);
+	}
+}
+
+#[test]
+fn wrapper_batch() {
+	check_batch::<3, 8>(Octaves::<_, 4>::new(Simplex::new(), 0.5, 0.5).seed(SEED));
+	check_batch::<2, 4>(AddNoise::new(ScaleNoise::new(Simplex::new(), 2.0, 0.5), Simplex::new()).seed(SEED));
+}
+
+#[test]
+fn combine_batch() {
+	check_batch::<2, 4>(MinNoise::new(Simplex::new(), Simplex::new()).seed(SEED));
+	check_batch::<2, 8>(MaxNoise::new(Simplex::new(), Simplex::new()).seed(SEED));
+	check_batch::<3, 8>(LerpNoise::new(Simplex::new(), Simplex::new(), Simplex::new()).seed(SEED));
+}
@@ -0,0 +1,58 @@
+//! Combining noise functions must pick, blend, and seed their inputs the way their docs say.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Simplex, Perlin, CurlNoise, Constant, MinNoise, MaxNoise, LerpNoise, noise::SplitSeed, helpers::IgnoreSeed};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+#[test]
+fn min_max() {
+	let a = Simplex::new().seed(SEED.split(0));
+	let b = Perlin::new().seed(SEED.split(1));
+	let min = MinNoise::new(Simplex::new(), Perlin::new()).seed(SEED);
+	let max = MaxNoise::new(Simplex::new(), Perlin::new()).seed(SEED);
+	for x in points::<3>(200) {
+		assert_eq!(min.noise(x), a.noise(x).min(b.noise(x)));
+		assert_eq!(max.noise(x), a.noise(x).max(b.noise(x)));
+	}
+}
+
+#[test]
+fn lerp_endpoints() {
+	let a = Simplex::new().seed(1);
+	let b = Perlin::new().seed(2);
+	let start = LerpNoise::new(a, b, Constant::new(0.));
+	let end = LerpNoise::new(a, b, Constant::new(1.));
+	let middle = LerpNoise::new(a, b, Constant::new(0.5));
+	for x in points::<3>(200) {
+		assert_eq!(start.noise(x), a.noise(x));
+		assert!((end.noise(x) - b.noise(x)).abs() < 1e-12);
+		assert!((middle.noise(x) - (a.noise(x) + b.noise(x)) / 2.).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn lerp_seeding() {
+	let lerp = LerpNoise::new(Simplex::new(), Perlin::new(), Simplex::new()).seed(SEED);
+	let a = Simplex::new().seed(SEED.split(0));
+	let b = Perlin::new().seed(SEED.split(1));
+	let control = Simplex::new().seed(SEED.split(2));
+	for x in points::<3>(200) {
+		let value1 = a.noise(x);
+		assert_eq!(lerp.noise(x), value1 + (b.noise(x) - value1) * control.noise(x));
+	}
+}
+
+#[test]
+fn lerp_vector() {
+	let curl = CurlNoise::<_, 3>::new(Simplex::new());
+	let zero = Vector::vector([0., 0., 0.]);
+	let lerp = LerpNoise::new(curl, IgnoreSeed::new(Constant::new(zero)), Simplex::new()).seed(SEED);
+	let curl = curl.seed(SEED.split(0));
+	let control = Simplex::new().seed(SEED.split(2));
+	for x in points::<3>(200) {
+		let value1 = curl.noise(x);
+		assert_eq!(lerp.noise(x), value1 + (zero - value1) * control.noise(x));
+	}
+}
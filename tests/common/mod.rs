@@ -0,0 +1,16 @@
+//! Fixtures shared between the integration tests.
+
+#![allow(dead_code)]
+
+use sized_matrix::Vector;
+
+pub const SEED: u64 = 0x5eed;
+
+/// `count` pseudorandom points in `[-5, 5)` along each axis, the same every run.
+pub fn points<const N: usize>(count: usize) -> impl Iterator<Item = Vector<f64, N>> {
+	let mut state = SEED;
+	(0..count).map(move |_| Vector::vector(core::array::from_fn(|_| {
+		state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+		(state >> 11) as f64 / (1u64 << 53) as f64 * 10. - 5.
+	})))
+}
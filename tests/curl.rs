@@ -0,0 +1,40 @@
+//! Curl noise must be divergence-free, with each component of a vector potential seeded separately.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, NoiseDerivative, Simplex, CurlNoise, noise::SplitSeed};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+const H: f64 = 1e-5;
+
+fn check_divergence<const N: usize>(noise: impl NoiseDomain<Vector<f64, N>, Value = Vector<f64, N>>) {
+	for x in points::<N>(200) {
+		let terms: [f64; N] = core::array::from_fn(|axis| {
+			let offset = Vector::<f64, N>::vector(core::array::from_fn(|i| if i == axis { H } else { 0. }));
+			(noise.noise(x + offset)[axis] - noise.noise(x - offset)[axis]) / (2. * H)
+		});
+		let divergence: f64 = terms.iter().sum();
+		let scale = terms.iter().map(|t| t.abs()).sum::<f64>().max(1.);
+		assert!(divergence.abs() < 1e-5 * scale, "{}D at {:?}: divergence {} from {:?}", N, x, divergence, terms);
+	}
+}
+
+#[test]
+fn divergence_free() {
+	check_divergence::<2>(CurlNoise::<_, 1>::new(Simplex::new()).seed(SEED));
+	check_divergence::<3>(CurlNoise::<_, 3>::new(Simplex::new()).seed(SEED));
+}
+
+#[test]
+fn potential_seeds() {
+	let curl = CurlNoise::<_, 3>::new(Simplex::new()).seed(SEED);
+	let potentials: [_; 3] = core::array::from_fn(|i| Simplex::new().seed(SEED.split(i)));
+	let by_hand = CurlNoise::from_potentials(potentials);
+	for x in points::<3>(100) {
+		assert_eq!(curl.noise(x), by_hand.noise(x));
+		
+		let [(_, dx), (_, dy), (_, dz)] = potentials.map(|potential| potential.noise_derivative(x));
+		assert!(dx != dy && dy != dz && dx != dz, "potentials match at {:?}", x);
+	}
+}
@@ -0,0 +1,55 @@
+//! Decorrelating octaves must only rotate and offset them, and must leave plain `Octaves::new` unchanged.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Simplex, Octaves, RandomTransform, FnNoise};
+use sized_matrix::Vector;
+use common::SEED;
+
+type Position<const N: usize> = FnNoise<fn(Vector<f64, N>) -> Vector<f64, N>, Vector<f64, N>>;
+
+// The transformed position is `rotation * x + offset`, so the columns of the rotation are `T(e_i) - T(0)`
+fn check_rotation<const N: usize>() where RandomTransform<Position<N>>: NoiseDomain<Vector<f64, N>, Value = Vector<f64, N>> {
+	for seed in 0..20 {
+		let position: Position<N> = FnNoise::new(|pos| pos);
+		let transform = RandomTransform::new(position).seed(seed);
+		let origin = transform.noise(Vector::vector([0.; N]));
+		let columns: [Vector<f64, N>; N] = core::array::from_fn(|i| {
+			transform.noise(Vector::vector(core::array::from_fn(|j| if i == j { 1. } else { 0. }))) - origin
+		});
+		assert!((columns[0][0] - 1.).abs() > 1e-6, "{}D seed {} wasn't rotated", N, seed);
+		for i in 0..N {
+			for j in 0..N {
+				let dot: f64 = (0..N).map(|k| columns[i][k] * columns[j][k]).sum();
+				let expected = if i == j { 1. } else { 0. };
+				assert!((dot - expected).abs() < 1e-12, "{}D seed {}: column {} . column {} = {}", N, seed, i, j, dot);
+			}
+		}
+	}
+}
+
+#[test]
+fn orthonormal() {
+	check_rotation::<2>();
+	check_rotation::<3>();
+	check_rotation::<4>();
+}
+
+#[test]
+fn plain_octaves_unchanged() {
+	// Recorded before `Octaves::decorrelated` was added
+	let expected: [(u64, u64); 4] = [
+		(0x3fc00c9f067a23cf, 0x3fc4bbe3e16477d7),
+		(0xbfd473abc8efc1aa, 0xbfda544a2dfa496b),
+		(0xbfd56e36d6ce3e78, 0xbfe0a669949a9166),
+		(0xbfd3618e452a4ae3, 0xbfd7bb9d870c40cc),
+	];
+	let a = Octaves::<_, 5>::new(Simplex::new(), 2.0, 0.5).seed(SEED);
+	let b = Octaves::<_, 4>::new(Simplex::new(), 1.9, 0.55).seed(SEED);
+	for (i, (expected_a, expected_b)) in expected.into_iter().enumerate() {
+		let x = Vector::vector([i as f64 * 1.37 - 2.1, 0.73 - i as f64 * 0.41, i as f64 * 0.29 + 0.5]);
+		// The `deterministic` feature can change the last bit
+		assert!((a.noise(x) - f64::from_bits(expected_a)).abs() < 1e-15, "at {:?}", x);
+		assert!((b.noise(x) - f64::from_bits(expected_b)).abs() < 1e-15, "at {:?}", x);
+	}
+}
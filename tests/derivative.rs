@@ -0,0 +1,50 @@
+//! Analytic derivatives must match central finite differences of the noise values.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDerivative, Simplex, Gradient, Constant, ScaleNoise, AddNoise, SumNoise, Octaves};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+const H: f64 = 1e-6;
+const TOLERANCE: f64 = 1e-5;
+
+fn check<const N: usize>(noise: impl NoiseDerivative<Vector<f64, N>, Value = f64, Derivative = Vector<f64, N>>) {
+	for x in points::<N>(200) {
+		let (value, derivative) = noise.noise_derivative(x);
+		assert!((value - noise.noise(x)).abs() < 1e-12);
+		for axis in 0..N {
+			let offset = Vector::<f64, N>::vector(core::array::from_fn(|i| if i == axis { H } else { 0. }));
+			let estimate = (noise.noise(x + offset) - noise.noise(x - offset)) / (2. * H);
+			assert!(
+				(estimate - derivative[axis]).abs() <= TOLERANCE * derivative[axis].abs().max(1.),
+				"{}D axis {} at {:?}: {} vs {}", N, axis, x, estimate, derivative[axis],
+			);
+		}
+	}
+}
+
+#[test]
+fn simplex() {
+	let simplex = Simplex::new().seed(SEED);
+	check::<1>(simplex);
+	check::<2>(simplex);
+	check::<3>(simplex);
+	check::<4>(simplex);
+}
+
+#[test]
+fn linear() {
+	check::<3>(Gradient::new(Vector::vector([0.3, -1.2, 2.5])));
+	check::<2>(Constant::new(0.7));
+}
+
+#[test]
+fn combinators() {
+	check::<2>(ScaleNoise::new(Simplex::new(), 2.5, 0.4).seed(SEED));
+	check::<3>(ScaleNoise::new(Simplex::new(), 0.3, -1.7).seed(SEED));
+	check::<3>(AddNoise::new(Simplex::new(), ScaleNoise::new(Simplex::new(), 3., 0.5)).seed(SEED));
+	check::<2>(SumNoise::new([ScaleNoise::new(Simplex::new(), 1., 1.), ScaleNoise::new(Simplex::new(), 1.9, 0.6), ScaleNoise::new(Simplex::new(), 4.1, 0.2)]).seed(SEED));
+	check::<3>(Octaves::<_, 4>::new(Simplex::new(), 2., 0.5).seed(SEED));
+	check::<4>(Octaves::<_, 3>::new(Simplex::new(), 1.7, 0.6).seed(SEED));
+}
@@ -0,0 +1,41 @@
+//! `DynOctaves` must give exactly the same values as the fixed-size `Octaves` and `SumNoise` it stands in for.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Simplex, Octaves, DynOctaves, SumNoise, ScaleNoise};
+use common::{SEED, points};
+
+const SEEDS: [u64; 3] = [0, SEED, u64::MAX];
+
+fn check_octaves<const N: usize>() {
+	for seed in SEEDS {
+		let fixed = Octaves::<_, N>::new(Simplex::new(), 1.9, 0.55).seed(seed);
+		let dynamic = DynOctaves::new(Simplex::new(), N, 1.9, 0.55).seed(seed);
+		for x in points::<3>(50) {
+			assert_eq!(dynamic.noise(x).to_bits(), fixed.noise(x).to_bits(), "{} octaves, seed {} at {:?}", N, seed, x);
+		}
+	}
+}
+
+#[test]
+fn same_as_octaves() {
+	check_octaves::<1>();
+	check_octaves::<2>();
+	check_octaves::<3>();
+	check_octaves::<5>();
+	check_octaves::<8>();
+}
+
+#[test]
+fn from_layers() {
+	let layers = [(1.3, 0.9), (2.7, -0.4), (0.5, 0.25)];
+	for seed in SEEDS {
+		let dynamic = DynOctaves::from_layers(Simplex::new(), layers).seed(seed);
+		let fixed = SumNoise::new(layers.map(|(frequency, amplitude)| ScaleNoise::new(Simplex::new(), frequency, amplitude))).seed(seed);
+		for x in points::<2>(50) {
+			assert_eq!(dynamic.noise(x).to_bits(), fixed.noise(x).to_bits(), "seed {} at {:?}", seed, x);
+		}
+	}
+}
@@ -0,0 +1,26 @@
+//! The `NoiseExt` methods must build the same noise functions as the combinators, without getting in the way of other types' methods.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, NoiseExt, Simplex, Perlin, Octaves, ScaleNoise, AddNoise};
+use sized_matrix::Vector;
+use common::SEED;
+
+#[test]
+fn chained() {
+	let chained = Simplex::new().octaves::<4>(0.5, 0.5).scale(2.0, 1.0).add(Perlin::new().scale(0.1, 0.3)).seed(SEED);
+	let nested = AddNoise::new(
+		ScaleNoise::new(Octaves::<_, 4>::new(Simplex::new(), 0.5, 0.5), 2.0, 1.0),
+		ScaleNoise::new(Perlin::new(), 0.1, 0.3),
+	).seed(SEED);
+	let x = Vector::vector([1.2, -3.5]);
+	assert_eq!(chained.noise(x), nested.noise(x));
+}
+
+#[test]
+fn other_types() {
+	// Only noise functions get the `NoiseExt` methods, so these aren't ambiguous
+	let doubled: Vec<i32> = [1, 2, 3].into_iter().map(|x| x * 2).collect();
+	assert_eq!(doubled, [2, 4, 6]);
+	assert_eq!(Some(2).map(|x| x + 1), Some(3));
+}
@@ -0,0 +1,28 @@
+//! Fixed-point simplex noise must closely follow the floating point version with the same seed.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Simplex};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+const ONE: f64 = 65536.;
+const TOLERANCE: f64 = 0.0005;
+
+fn check<const N: usize>() where Simplex<i32>: NoiseDomain<Vector<i32, N>, Value = i32>, Simplex: NoiseDomain<Vector<f64, N>, Value = f64> {
+	let fixed = Simplex::new_fixed().seed(SEED);
+	let float = Simplex::new().seed(SEED);
+	for point in points::<N>(5000) {
+		let fixed_point = Vector::vector(core::array::from_fn(|i| (point[i] * ONE).round() as i32));
+		let point = Vector::vector(core::array::from_fn(|i| fixed_point[i] as f64 / ONE));
+		let error = (fixed.noise(fixed_point) as f64 / ONE - float.noise(point)).abs();
+		assert!(error < TOLERANCE, "{}D at {:?}: fixed {} vs float {}", N, point, fixed.noise(fixed_point) as f64 / ONE, float.noise(point));
+	}
+}
+
+#[test]
+fn matches_float() {
+	check::<2>();
+	check::<3>();
+	check::<4>();
+}
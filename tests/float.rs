@@ -0,0 +1,81 @@
+//! Evaluating in `f32` must give approximately the same values as evaluating in `f64`.
+
+mod common;
+
+use noise_fn::{
+	Seedable, NoiseDomain, Simplex, Perlin, ValueNoise, Interpolation, Worley, WorleyDistance, WorleyReturn,
+	OpenSimplex2, OpenSimplex2S, OpenSimplexOrientation, Octaves, Billow, RidgedMulti, HybridMulti, HeteroMulti,
+	CurlNoise, Warp, Periodic,
+};
+#[cfg(feature = "alloc")]
+use noise_fn::DynOctaves;
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+fn check<const N: usize>(f32_noise: impl NoiseDomain<Vector<f32, N>, Value = f32>, f64_noise: impl NoiseDomain<Vector<f64, N>, Value = f64>) {
+	for point in points::<N>(200) {
+		let expected = f64_noise.noise(point);
+		let actual = f32_noise.noise(Vector::vector(core::array::from_fn(|j| point[j] as f32)));
+		assert!((actual as f64 - expected).abs() < 1e-3, "at {:?}: got {}, expected {}", point, actual, expected);
+	}
+}
+
+#[test]
+fn gradient_noise() {
+	check::<3>(Simplex::<f32>::new().seed(SEED), Simplex::<f64>::new().seed(SEED));
+	check::<1>(Perlin::<f32>::new().seed(SEED), Perlin::<f64>::new().seed(SEED));
+	check::<4>(Perlin::<f32>::new().seed(SEED), Perlin::<f64>::new().seed(SEED));
+	check::<3>(ValueNoise::new_f32(Interpolation::Quintic).seed(SEED), ValueNoise::new(Interpolation::Quintic).seed(SEED));
+}
+
+#[test]
+fn worley() {
+	for distance in [WorleyDistance::Euclidean, WorleyDistance::Manhattan, WorleyDistance::Chebyshev] {
+		check::<2>(Worley::new(distance, WorleyReturn::F1, 1.0f32).seed(SEED), Worley::new(distance, WorleyReturn::F1, 1.0).seed(SEED));
+		check::<3>(Worley::new(distance, WorleyReturn::F2, 0.8f32).seed(SEED), Worley::new(distance, WorleyReturn::F2, 0.8).seed(SEED));
+	}
+}
+
+#[test]
+fn open_simplex() {
+	let orientation = OpenSimplexOrientation::ImproveXY;
+	check::<2>(OpenSimplex2::<_, f32>::new(orientation).seed(SEED), OpenSimplex2::<_, f64>::new(orientation).seed(SEED));
+	check::<3>(OpenSimplex2::<_, f32>::new(orientation).seed(SEED), OpenSimplex2::<_, f64>::new(orientation).seed(SEED));
+	check::<3>(OpenSimplex2S::<_, f32>::new(orientation).seed(SEED), OpenSimplex2S::<_, f64>::new(orientation).seed(SEED));
+	check::<4>(OpenSimplex2S::<_, f32>::new(orientation).seed(SEED), OpenSimplex2S::<_, f64>::new(orientation).seed(SEED));
+}
+
+#[test]
+fn fractals() {
+	check::<2>(Octaves::<_, 4, f32>::decorrelated(Perlin::new(), 2.0, 0.5).seed(SEED), Octaves::<_, 4>::decorrelated(Perlin::new(), 2.0, 0.5).seed(SEED));
+	check::<3>(Billow::<_, 4, f32>::new(Simplex::new(), 2.0, 0.5).seed(SEED), Billow::<_, 4>::new(Simplex::new(), 2.0, 0.5).seed(SEED));
+	check::<3>(RidgedMulti::<_, 4, f32>::new(Simplex::new(), 2.0, 0.5, 1.0, 2.0).seed(SEED), RidgedMulti::<_, 4>::new(Simplex::new(), 2.0, 0.5, 1.0, 2.0).seed(SEED));
+	check::<3>(HybridMulti::<_, 4, f32>::new(Simplex::new(), 2.0, 0.5, 0.7).seed(SEED), HybridMulti::<_, 4>::new(Simplex::new(), 2.0, 0.5, 0.7).seed(SEED));
+	check::<3>(HeteroMulti::<_, 4, f32>::new(Simplex::new(), 2.0, 0.5, 0.7).seed(SEED), HeteroMulti::<_, 4>::new(Simplex::new(), 2.0, 0.5, 0.7).seed(SEED));
+}
+
+#[test]
+fn dyn_octaves_and_periodic() {
+	#[cfg(feature = "alloc")]
+	check::<3>(DynOctaves::<_, f32>::new(Simplex::new(), 4, 2.0, 0.5).seed(SEED), DynOctaves::<_, f64>::new(Simplex::new(), 4, 2.0, 0.5).seed(SEED));
+	check::<2>(Periodic::<_, 2, f32>::new(Perlin::new(), Vector::vector([4., 6.5])).seed(SEED), Periodic::<_, 2>::new(Perlin::new(), Vector::vector([4., 6.5])).seed(SEED));
+	check::<3>(Periodic::<_, 3, f32>::new(Simplex::new(), Vector::vector([4., 5., 6.])).seed(SEED), Periodic::<_, 3>::new(Simplex::new(), Vector::vector([4., 5., 6.])).seed(SEED));
+}
+
+#[test]
+fn curl_and_warp() {
+	let curl_f32 = CurlNoise::<_, 3, f32>::new(Simplex::new()).seed(SEED);
+	let curl_f64 = CurlNoise::<_, 3>::new(Simplex::new()).seed(SEED);
+	for point in points::<3>(200) {
+		let expected = curl_f64.noise(point);
+		let actual = curl_f32.noise(Vector::vector(core::array::from_fn(|j| point[j] as f32)));
+		for i in 0..3 {
+			assert!((actual[i] as f64 - expected[i]).abs() < 1e-2, "at {:?}: got {:?}, expected {:?}", point, actual, expected);
+		}
+	}
+	
+	check::<3>(
+		Warp::new(Simplex::new(), CurlNoise::<_, 3, f32>::new(Simplex::new()), 0.1f32).seed(SEED),
+		Warp::new(Simplex::new(), CurlNoise::<_, 3>::new(Simplex::new()), 0.1).seed(SEED),
+	);
+}
@@ -0,0 +1,48 @@
+//! The multifractal noise functions must stay within their expected ranges and be determined by their seed.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Simplex, Billow, RidgedMulti, HybridMulti, HeteroMulti};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+fn check_range(noise: impl NoiseDomain<Vector<f64, 3>, Value = f64>, min: f64, max: f64) {
+	let mut values = points::<3>(1000).map(|x| noise.noise(x));
+	assert!(values.all(|value| (min..=max).contains(&value)));
+}
+
+fn check_seeding<C: Seedable<Seed = u64> + Copy>(config: C) where C::Seeded: NoiseDomain<Vector<f64, 3>, Value = f64> {
+	let (a, b, other) = (config.seed(SEED), config.seed(SEED), config.seed(SEED + 1));
+	assert!(points::<3>(1000).all(|x| a.noise(x).to_bits() == b.noise(x).to_bits()));
+	assert!(points::<3>(1000).any(|x| a.noise(x) != other.noise(x)));
+}
+
+#[test]
+fn billow() {
+	let config = Billow::<_, 4>::new(Simplex::new(), 2.0, 0.5);
+	check_range(config.seed(SEED), -1.875, 1.875);
+	check_seeding(config);
+}
+
+#[test]
+fn ridged() {
+	let config = RidgedMulti::<_, 4>::new(Simplex::new(), 2.0, 0.5, 1.0, 2.0);
+	check_range(config.seed(SEED), 0., 1.875);
+	check_seeding(config);
+}
+
+#[test]
+fn hybrid() {
+	let config = HybridMulti::<_, 4>::new(Simplex::new(), 2.0, 0.5, 0.7);
+	// The weight never exceeds 1, so each octave contributes at most 1.7 times its amplitude
+	check_range(config.seed(SEED), -3.1875, 3.1875);
+	check_seeding(config);
+}
+
+#[test]
+fn hetero() {
+	let config = HeteroMulti::<_, 4>::new(Simplex::new(), 2.0, 0.5, 0.7);
+	// Octave i multiplies the sum by at most 1 + 1.7 * 0.5^i
+	check_range(config.seed(SEED), -1., 5.5);
+	check_seeding(config);
+}
@@ -0,0 +1,207 @@
+//! Golden values locking down the output of the core noise functions, compared bit for bit.
+//!
+//! The integer and fixed-point outputs are the same in every configuration. The floating point outputs are only guaranteed bit for bit
+//! with the `deterministic` feature, so without it they are compared to within `TOLERANCE` instead, e.g. to allow for fused multiply-add.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, HashNoise, ToFloat, Simplex, Octaves, FixedValueNoise, Interpolation, noise::SplitSeed};
+use sized_matrix::Vector;
+use common::SEED;
+
+#[cfg(not(feature = "deterministic"))]
+const TOLERANCE: f64 = 1e-9;
+
+const LATTICE: [[i64; 3]; 8] = [
+	[0, 0, -4],
+	[7919, -104729, -3],
+	[15838, -209458, -2],
+	[23757, -314187, -1],
+	[31676, -418916, 0],
+	[39595, -523645, 1],
+	[47514, -628374, 2],
+	[55433, -733103, 3],
+];
+
+const FIXED_POINTS: [[i32; 4]; 8] = [
+	[0, 0, 0, 0],
+	[32768, -81920, 180224, -229376],
+	[203162, 176947, 91750, 111411],
+	[-66, 131, -197, 262],
+	[808714, -3721134, 5906104, -2264924],
+	[-17793024, 54280192, -11976704, 55410688],
+	[80908766, -574451146, 278031352, -72817776],
+	[2147483647, -2147483648, 1234567890, -987654321],
+];
+
+const POINTS: [[f64; 4]; 8] = [
+	[0.0, 0.0, 0.0, 0.0],
+	[0.5, -1.25, 2.75, -3.5],
+	[3.1, 2.7, 1.4, 1.7],
+	[-0.001, 0.002, -0.003, 0.004],
+	[12.34, -56.78, 90.12, -34.56],
+	[-271.5, 828.25, -182.75, 845.5],
+	[1234.5678, -8765.4321, 4242.4242, -1111.1111],
+	[100000.3, -100000.7, 33333.3, -77777.7],
+];
+
+fn check_f64<const N: usize>(noise: impl NoiseDomain<Vector<f64, N>, Value = f64>, expected: [u64; 8]) {
+	for (point, expected) in POINTS.iter().zip(expected) {
+		let pos = Vector::vector(<[f64; N]>::try_from(&point[..N]).unwrap());
+		let actual = noise.noise(pos);
+		#[cfg(feature = "deterministic")]
+		assert_eq!(actual.to_bits(), expected, "at {:?}: got {}, expected {}", &point[..N], actual, f64::from_bits(expected));
+		#[cfg(not(feature = "deterministic"))]
+		assert!((actual - f64::from_bits(expected)).abs() <= TOLERANCE, "at {:?}: got {}, expected {}", &point[..N], actual, f64::from_bits(expected));
+	}
+}
+
+fn check_fixed<const N: usize>(noise: impl NoiseDomain<Vector<i32, N>, Value = i32>, expected: [i32; 8]) {
+	for (point, expected) in FIXED_POINTS.iter().zip(expected) {
+		let pos = Vector::vector(<[i32; N]>::try_from(&point[..N]).unwrap());
+		assert_eq!(noise.noise(pos), expected, "at {:?}", &point[..N]);
+	}
+}
+
+#[test]
+fn hash_noise() {
+	let noise = HashNoise::new().seed(SEED);
+	let expected: [u64; 8] = [
+		0xf4f22dd1005f60b5,
+		0x3b61072e277fdf3d,
+		0x3961304266f8b526,
+		0xe293fe283b52369f,
+		0x8d5195d971b7ca7b,
+		0x0240785a503f274e,
+		0x8645a12b9b89baa7,
+		0xf8b9d9ddc79b2a94,
+	];
+	for (pos, expected) in LATTICE.iter().zip(expected) {
+		assert_eq!(noise.noise(Vector::vector(*pos)), expected, "at {:?}", pos);
+	}
+}
+
+#[test]
+fn split_seed() {
+	let expected: [u64; 8] = [
+		0x0ad19774f7db1598,
+		0x142a83ac1b73ab1d,
+		0x62cd0d33afecf801,
+		0x4c86af3fa8945836,
+		0x26b6bcb37590bac5,
+		0xa99986935a43acc0,
+		0x7c7808360bd2b171,
+		0x85bef5ce0fe68cac,
+	];
+	for (n, expected) in expected.into_iter().enumerate() {
+		assert_eq!(SEED.split(n), expected, "child {}", n);
+	}
+}
+
+#[test]
+fn to_float() {
+	let noise = ToFloat::new(HashNoise::new()).seed(SEED);
+	let expected: [u64; 8] = [
+		0x3fee9e45ba200bec,
+		0x3fcdb0839713bfef,
+		0x3fccb09821337c5a,
+		0x3fec527fc5076a46,
+		0x3fe1aa32bb2e36f9,
+		0x3f8203c2d281f93a,
+		0x3fe0c8b425737137,
+		0x3fef173b3bb8f365,
+	];
+	for (pos, expected) in LATTICE.iter().zip(expected) {
+		assert_eq!(noise.noise(Vector::vector(*pos)).to_bits(), expected, "at {:?}", pos);
+	}
+}
+
+#[test]
+fn simplex_2d() {
+	check_f64::<2>(Simplex::new().seed(SEED), [
+		0x0000000000000000,
+		0xbfe1840cd66dbc4e,
+		0x3fe054b308708f37,
+		0x3f71eb5624fdf106,
+		0xbfb0dc8a6f0e413b,
+		0x3fe18d4925f12807,
+		0x3fc9069eeef13d52,
+		0x3fd5d957f723dc3c,
+	]);
+}
+
+#[test]
+fn simplex_3d() {
+	check_f64::<3>(Simplex::new().seed(SEED), [
+		0x0000000000000000,
+		0x3fd56d255e34d106,
+		0xbfb1fde69d558e15,
+		0x3f897a5f6f32a3f6,
+		0x3fc4d905312dfcf4,
+		0xbfe8918f1f511360,
+		0xbfe0e12d0901633c,
+		0xbfe86314f2f09b2d,
+	]);
+}
+
+#[test]
+fn simplex_4d() {
+	check_f64::<4>(Simplex::new().seed(SEED), [
+		0x0000000000000000,
+		0xbfb9ff6e29806191,
+		0xbfc092b75049c232,
+		0xbf8ca8e2ffecf964,
+		0xbfdc95831f1560d4,
+		0xbfa64551bc2bca5c,
+		0xbfc739627aabb6af,
+		0x3fc33c1c47f416de,
+	]);
+}
+
+#[test]
+fn octaves_2d() {
+	check_f64::<2>(Octaves::<_, 6>::new(Simplex::new(), 0.5, 0.5).seed(SEED), [
+		0x0000000000000000,
+		0xbfcc898964ebbd11,
+		0xbfe54f2504211762,
+		0x3f8038d9c7f6e8ea,
+		0x3fda3b1a5321a7a5,
+		0xbfa665d0046e79c6,
+		0xbfcaa866c7fa2b3b,
+		0x3fecf925d8bf3cc3,
+	]);
+}
+
+#[test]
+fn octaves_3d() {
+	check_f64::<3>(Octaves::<_, 6>::new(Simplex::new(), 0.5, 0.5).seed(SEED), [
+		0x0000000000000000,
+		0x3fd20400b293c6e4,
+		0x3fc5526feee667e6,
+		0x3f91ae88b71d3400,
+		0x3fde836cad0ba060,
+		0x3fd320b668a669d3,
+		0x3fea8dcf57b7ee66,
+		0xbfc085ff9ed20726,
+	]);
+}
+
+#[test]
+fn fixed_simplex_2d() {
+	check_fixed::<2>(Simplex::new_fixed().seed(SEED), [0, -35878, 33444, 293, -4306, 35946, 13312, -9]);
+}
+
+#[test]
+fn fixed_simplex_3d() {
+	check_fixed::<3>(Simplex::new_fixed().seed(SEED), [0, 21941, -4610, 817, 10674, -50323, -33817, -42110]);
+}
+
+#[test]
+fn fixed_simplex_4d() {
+	check_fixed::<4>(Simplex::new_fixed().seed(SEED), [0, -6658, -8492, -914, -29274, -2853, -11352, 41786]);
+}
+
+#[test]
+fn fixed_value_noise_3d() {
+	check_fixed::<3>(FixedValueNoise::new(Interpolation::Quintic).seed(SEED), [3677, 6987, 40638, 3675, -24412, -41224, -804, -60220]);
+}
@@ -0,0 +1,136 @@
+//! Grid fills must give exactly the same values as evaluating each point separately.
+
+mod common;
+
+use noise_fn::{Noise, Seedable, NoiseDomain, GridArg, Simplex, Perlin, Octaves, ScaleNoise, AddNoise};
+use sized_matrix::Vector;
+use common::SEED;
+
+fn check_grid<const N: usize>(noise: impl NoiseDomain<Vector<f64, N>, Value = f64>, size: [usize; N]) {
+	let origin = Vector::vector([-1.3; N]);
+	let step = Vector::vector([0.07; N]);
+	let mut out = vec![0.0; Vector::<f64, N>::grid_len(size)];
+	noise.fill_grid(origin, step, size, &mut out);
+	for (index, value) in out.into_iter().enumerate() {
+		let expected = noise.noise(Vector::grid_point(origin, step, size, index));
+		assert_eq!(value.to_bits(), expected.to_bits(), "at index {}", index);
+	}
+}
+
+#[test]
+fn simplex_grid() {
+	let simplex = Simplex::new().seed(SEED);
+	check_grid(simplex, [37]);
+	check_grid(simplex, [40, 30]);
+	check_grid(simplex, [12, 10, 8]);
+	check_grid(simplex, [6, 5, 4, 3]);
+}
+
+#[test]
+fn default_grid() {
+	check_grid(Perlin::new().seed(SEED), [12, 10, 8]);
+}
+
+// Panics if evaluated one point at a time, to check that grid fills reach the inner `noise_points`.
+#[derive(Copy, Clone)]
+struct PointsOnly<Inner>(Inner);
+
+impl<Inner: Noise> Noise for PointsOnly<Inner> {
+	type Value = Inner::Value;
+}
+
+impl<Arg, Inner: NoiseDomain<Arg>> NoiseDomain<Arg> for PointsOnly<Inner> {
+	fn noise(&self, _arg: Arg) -> Inner::Value {
+		panic!("evaluated one point at a time")
+	}
+	
+	fn noise_points<Points: Iterator<Item = Arg> + Clone>(&self, points: Points) -> impl Iterator<Item = Inner::Value> {
+		self.0.noise_points(points)
+	}
+}
+
+#[test]
+fn octaves_grid() {
+	let simplex = Simplex::new().seed(SEED);
+	let octaves = Octaves::<_, 4>::new(simplex, 2.0, 0.5);
+	check_grid(octaves, [40, 30]);
+	check_grid(octaves, [12, 10, 8]);
+	check_grid(Octaves::<_, 4>::new(Simplex::new(), 2.0, 0.5).seed(SEED), [12, 10, 8]);
+	
+	let (origin, step, size) = (Vector::vector([0.3, -2.0, 1.1]), Vector::vector([0.05, 0.1, 0.2]), [8, 7, 6]);
+	let mut expected = [0.0; 336];
+	AddNoise::new(octaves, ScaleNoise::new(simplex, 0.3, 2.0)).fill_grid(origin, step, size, &mut expected);
+	let mut forwarded = [0.0; 336];
+	AddNoise::new(Octaves::<_, 4>::new(PointsOnly(simplex), 2.0, 0.5), ScaleNoise::new(PointsOnly(simplex), 0.3, 2.0)).fill_grid(origin, step, size, &mut forwarded);
+	assert_eq!(expected.map(f64::to_bits), forwarded.map(f64::to_bits));
+}
+
+#[test]
+fn grid_parts() {
+	let simplex = Simplex::new().seed(SEED);
+	let (origin, step, size) = (Vector::vector([0.3, -2.0, 1.1]), Vector::vector([0.05, 0.1, 0.2]), [8, 7, 6]);
+	let mut whole = [0.0; 336];
+	simplex.fill_grid(origin, step, size, &mut whole);
+	let mut parts = [0.0; 336];
+	for (i, part) in parts.chunks_mut(50).enumerate() {
+		simplex.fill_grid_part(origin, step, size, i * 50, part);
+	}
+	assert_eq!(whole.map(f64::to_bits), parts.map(f64::to_bits));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_grid() {
+	let simplex = Simplex::new().seed(SEED);
+	let (origin, step, size) = (Vector::vector([0.3, -2.0, 1.1]), Vector::vector([0.05, 0.1, 0.2]), [32, 32, 32]);
+	let mut serial = vec![0.0f64; 32 * 32 * 32];
+	simplex.fill_grid(origin, step, size, &mut serial);
+	let mut parallel = vec![0.0; 32 * 32 * 32];
+	simplex.par_fill_grid(origin, step, size, &mut parallel);
+	assert!(serial.iter().zip(&parallel).all(|(a, b)| a.to_bits() == b.to_bits()));
+}
+
+#[test]
+fn chunk_boundaries() {
+	// Two neighbouring chunks of 32 cells, each including the points on both faces
+	let simplex = Simplex::new().seed(SEED);
+	let step = Vector::vector([0.0625; 3]);
+	let (origin_a, origin_b) = (Vector::vector([-1.0, 0.5, 2.0]), Vector::vector([1.0, 0.5, 2.0]));
+	let size = [33; 3];
+	let mut chunk_a = vec![0.0f64; 33 * 33 * 33];
+	let mut chunk_b = vec![0.0f64; 33 * 33 * 33];
+	simplex.fill_grid(origin_a, step, size, &mut chunk_a);
+	simplex.fill_grid(origin_b, step, size, &mut chunk_b);
+	
+	for z in 0..33 {
+		for y in 0..33 {
+			let (face_a, face_b) = (32 + 33 * (y + 33 * z), 33 * (y + 33 * z));
+			let point = Vector::grid_point(origin_b, step, size, face_b);
+			assert_eq!(point, Vector::grid_point(origin_a, step, size, face_a));
+			assert_eq!(chunk_a[face_a].to_bits(), simplex.noise(point).to_bits(), "at {:?}", point);
+			assert_eq!(chunk_b[face_b].to_bits(), simplex.noise(point).to_bits(), "at {:?}", point);
+		}
+	}
+}
+
+#[test]
+fn grid_order() {
+	let simplex = Simplex::new().seed(SEED);
+	let mut out = [0.0; 6];
+	simplex.fill_grid(Vector::vector([0.5, 0.25]), Vector::vector([0.1, 0.2]), [3, 2], &mut out);
+	assert_eq!(out[4], simplex.noise(Vector::vector([0.5 + 0.1 * 1., 0.25 + 0.2 * 1.])));
+}
+
+#[test]
+#[should_panic]
+fn grid_part_overflow() {
+	let mut out = [0.0; 5];
+	Simplex::new().seed(SEED).fill_grid_part(Vector::vector([0.0, 0.0]), Vector::vector([1.0, 1.0]), [2, 2], usize::MAX, &mut out);
+}
+
+#[test]
+#[should_panic]
+fn grid_size_mismatch() {
+	let mut out = [0.0; 5];
+	Simplex::new().seed(SEED).fill_grid(Vector::vector([0.0, 0.0]), Vector::vector([1.0, 1.0]), [2, 2], &mut out);
+}
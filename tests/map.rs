@@ -0,0 +1,71 @@
+//! The closure combinators must give the same values as calling the closures by hand, including once seeded and nested.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, NoiseExt, Simplex, Perlin, Octaves, ScaleNoise, MapNoise, MapArg, FnNoise, SeedFnNoise, noise::SplitSeed};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+fn shape(value: f64) -> f64 {
+	value * value * 0.5 - value
+}
+
+fn warp(pos: Vector<f64, 2>) -> Vector<f64, 2> {
+	Vector::vector([pos[0] + pos[1] * 0.3, pos[1] - 1.])
+}
+
+fn field(pos: Vector<f64, 2>) -> f64 {
+	(pos[0] * 0.7).sin() * pos[1].cos()
+}
+
+#[test]
+fn seeded() {
+	let simplex = Simplex::new().seed(SEED);
+	let perlin = Perlin::new().seed(SEED);
+	let map = MapNoise::new(Simplex::new(), shape).seed(SEED);
+	let map_ext = Simplex::new().map(shape).seed(SEED);
+	let map_arg = MapArg::new(Perlin::new(), warp).seed(SEED);
+	let fn_noise = FnNoise::new(field).seed(SEED);
+	let seed_fn = SeedFnNoise::new(|seed: u64| move |pos: Vector<f64, 2>| field(pos) + (seed % 7) as f64).seed(SEED);
+	for x in points::<2>(200) {
+		assert_eq!(map.noise(x), shape(simplex.noise(x)));
+		assert_eq!(map_ext.noise(x), shape(simplex.noise(x)));
+		assert_eq!(map_arg.noise(x), perlin.noise(warp(x)));
+		assert_eq!(fn_noise.noise(x), field(x));
+		assert_eq!(seed_fn.noise(x), field(x) + (SEED % 7) as f64);
+	}
+}
+
+#[test]
+fn scaled() {
+	let simplex = Simplex::new().seed(SEED);
+	let map = ScaleNoise::new(MapNoise::new(Simplex::new(), shape), 2., 0.5).seed(SEED);
+	let fn_noise = ScaleNoise::new(FnNoise::new(field), 2., 0.5).seed(SEED);
+	for x in points::<2>(200) {
+		assert_eq!(map.noise(x), shape(simplex.noise(x * 2.)) * 0.5);
+		assert_eq!(fn_noise.noise(x), field(x * 2.) * 0.5);
+	}
+}
+
+#[test]
+fn octaves() {
+	let map = Octaves::<_, 3>::new(MapNoise::new(Simplex::new(), shape), 2., 0.5).seed(SEED);
+	let map_arg = Octaves::<_, 3>::new(MapArg::new(Perlin::new(), warp), 2., 0.5).seed(SEED);
+	let fn_noise = Octaves::<_, 3>::new(FnNoise::new(field), 2., 0.5).seed(SEED);
+	let seed_fn = Octaves::<_, 3>::new(SeedFnNoise::new(|seed: u64| move |pos: Vector<f64, 2>| field(pos) + (seed % 7) as f64), 2., 0.5).seed(SEED);
+	for x in points::<2>(200) {
+		let (mut expected_map, mut expected_map_arg, mut expected_fn, mut expected_seed_fn) = (0., 0., 0., 0.);
+		for i in 0..3 {
+			let (frequency, amplitude) = ((1 << i) as f64, 0.5f64.powi(i));
+			let seed = SEED.split(i as usize);
+			expected_map += shape(Simplex::new().seed(seed).noise(x * frequency)) * amplitude;
+			expected_map_arg += Perlin::new().seed(seed).noise(warp(x * frequency)) * amplitude;
+			expected_fn += field(x * frequency) * amplitude;
+			expected_seed_fn += (field(x * frequency) + (seed % 7) as f64) * amplitude;
+		}
+		assert_eq!(map.noise(x), expected_map);
+		assert_eq!(map_arg.noise(x), expected_map_arg);
+		assert_eq!(fn_noise.noise(x), expected_fn);
+		assert_eq!(seed_fn.noise(x), expected_seed_fn);
+	}
+}
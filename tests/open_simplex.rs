@@ -0,0 +1,54 @@
+//! OpenSimplex2 and OpenSimplex2S must stay within [-1, 1] and be continuous, which they only are if every lattice vertex
+//! within the kernel radius contributes.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, OpenSimplex2, OpenSimplex2S, OpenSimplexOrientation};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+const ORIENTATIONS: [OpenSimplexOrientation; 3] = [OpenSimplexOrientation::Standard, OpenSimplexOrientation::ImproveXY, OpenSimplexOrientation::ImproveXZ];
+
+// Walking in small steps crosses many lattice cells, where a missing vertex would make the noise jump. The noise never
+// changes faster than about 6 per unit, so a smooth step can't change it by more than `MAX_CHANGE`.
+const STEP: f64 = 1e-3;
+const MAX_CHANGE: f64 = 0.02;
+
+fn check<const N: usize>(noise: impl NoiseDomain<Vector<f64, N>, Value = f64>, name: &str) {
+	let direction: [f64; N] = core::array::from_fn(|i| [0.83, 0.51, -0.62, 0.37][i]);
+	let length = direction.iter().map(|d| d * d).sum::<f64>().sqrt();
+	let step = Vector::vector(direction.map(|d| d * STEP / length));
+	
+	for start in points::<N>(8) {
+		let mut point = start;
+		let mut value = noise.noise(point);
+		for _ in 0..1000 {
+			point += step;
+			let next = noise.noise(point);
+			assert!((next - value).abs() <= MAX_CHANGE, "{} {}D jumped by {} at {:?}", name, N, (next - value).abs(), point);
+			value = next;
+		}
+	}
+	
+	let max = points::<N>(4000).map(|point| noise.noise(point).abs()).fold(0., f64::max);
+	assert!(max <= 1., "{} {}D reached {}", name, N, max);
+	assert!(max >= 0.6, "{} {}D only reached {}", name, N, max);
+}
+
+#[test]
+fn open_simplex_2() {
+	check::<2>(OpenSimplex2::new(OpenSimplexOrientation::Standard).seed(SEED), "OpenSimplex2");
+	for orientation in ORIENTATIONS {
+		check::<3>(OpenSimplex2::new(orientation).seed(SEED), "OpenSimplex2");
+	}
+	check::<4>(OpenSimplex2::new(OpenSimplexOrientation::Standard).seed(SEED), "OpenSimplex2");
+}
+
+#[test]
+fn open_simplex_2s() {
+	check::<2>(OpenSimplex2S::new(OpenSimplexOrientation::Standard).seed(SEED), "OpenSimplex2S");
+	for orientation in ORIENTATIONS {
+		check::<3>(OpenSimplex2S::new(orientation).seed(SEED), "OpenSimplex2S");
+	}
+	check::<4>(OpenSimplex2S::new(OpenSimplexOrientation::Standard).seed(SEED), "OpenSimplex2S");
+}
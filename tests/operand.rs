@@ -0,0 +1,95 @@
+//! Every exported noise function and configuration must support the operators, so this fails to compile if one is missing.
+
+mod common;
+
+use noise_fn::{
+	Seedable, NoiseOperand, WhiteNoise, HashNoise, Simplex, Perlin, Worley, WorleyDistance, WorleyReturn, ValueNoise, Interpolation,
+	FixedValueNoise, OpenSimplex2, OpenSimplex2S, OpenSimplexOrientation, ScaleNoise, ScaleValue, AddNoise, SubNoise, MulNoise,
+	NegNoise, MinNoise, MaxNoise, LerpNoise, MapNoise, MapArg, FnNoise, SeedFnNoise, Clamp, Abs, Pow, Terrace, Curve, Constant,
+	Gradient, SumNoise, Octaves, Billow, RidgedMulti, HybridMulti, HeteroMulti, RandomTransform, ToFloat, CurlNoise, Warp, Periodic,
+	helpers::{EmptyConfig, IgnoreSeed},
+};
+#[cfg(feature = "alloc")]
+use noise_fn::{DynSumNoise, DynOctaves};
+use sized_matrix::Vector;
+use core::ops::{Add, Sub, Mul, Neg};
+use common::SEED;
+
+trait Operators: NoiseOperand + Add<Constant<f64>> + Sub<Constant<f64>> + Mul<Constant<f64>> + Neg { }
+
+impl<T: NoiseOperand + Add<Constant<f64>> + Sub<Constant<f64>> + Mul<Constant<f64>> + Neg> Operators for T { }
+
+fn operand<T: Operators>(_noise: T) { }
+
+// Noise functions with floating point values also get `* f64`.
+fn scaled<T: Operators + Mul<f64, Output = ScaleValue<T>>>(_noise: T) { }
+
+fn simplex() -> EmptyConfig<Simplex> {
+	Simplex::new()
+}
+
+fn perlin() -> EmptyConfig<Perlin> {
+	Perlin::new()
+}
+
+#[test]
+fn generators() {
+	operand(WhiteNoise::new());
+	operand(WhiteNoise::new().seed(SEED));
+	operand(HashNoise::new().seed(SEED));
+	scaled(simplex());
+	scaled(simplex().seed(SEED));
+	operand(Simplex::new_fixed().seed(SEED));
+	scaled(perlin().seed(SEED));
+	scaled(Worley::new(WorleyDistance::Euclidean, WorleyReturn::F1, 1.0).seed(SEED));
+	scaled(ValueNoise::new(Interpolation::Quintic).seed(SEED));
+	operand(FixedValueNoise::new(Interpolation::Quintic).seed(SEED));
+	scaled(OpenSimplex2::<_, f64>::new(OpenSimplexOrientation::Standard).seed(SEED));
+	scaled(OpenSimplex2S::<_, f64>::new(OpenSimplexOrientation::Standard).seed(SEED));
+	scaled(Constant::new(1.0));
+	scaled(Gradient::new(Vector::vector([1.0, 2.0])));
+	scaled(FnNoise::new(|x: f64| x));
+	scaled(SeedFnNoise::new(|seed: u64| move |x: f64| x + seed as f64));
+	scaled(SeedFnNoise::new(|seed: u64| move |x: f64| x + seed as f64).seed(SEED));
+	scaled(IgnoreSeed::<u64, _>::new(Constant::new(1.0)));
+}
+
+#[test]
+fn combinators() {
+	scaled(ScaleNoise::new(simplex(), 2.0, 0.5));
+	scaled(ScaleValue::new(simplex(), 0.5));
+	scaled(AddNoise::new(simplex(), perlin()));
+	scaled(SubNoise::new(simplex(), perlin()));
+	scaled(MulNoise::new(simplex(), perlin()));
+	scaled(NegNoise::new(simplex()));
+	scaled(MinNoise::new(simplex(), perlin()));
+	scaled(MaxNoise::new(simplex(), perlin()));
+	scaled(LerpNoise::new(simplex(), perlin(), Constant::new(0.5)));
+	scaled(MapNoise::new(simplex(), |x: f64| x * x));
+	scaled(MapArg::new(simplex(), |x: f64| x * x));
+	scaled(Clamp::new(simplex(), -0.5, 0.5));
+	scaled(Abs::new(simplex()));
+	scaled(Pow::new(simplex(), 2.0));
+	scaled(Terrace::new(simplex(), [-1.0, 0.0, 1.0], false));
+	scaled(Curve::new(simplex(), [(-1.0, -1.0), (0.0, 0.5), (1.0, 1.0)]));
+	scaled(RandomTransform::<_, f64>::new(simplex()));
+	scaled(ToFloat::new(HashNoise::new()));
+	operand(CurlNoise::<_, 3>::new(simplex()));
+	scaled(Warp::new(simplex(), CurlNoise::<_, 3>::new(simplex()), 0.1));
+	scaled(Periodic::<_, 2>::new(perlin(), Vector::vector([4.0, 4.0])));
+}
+
+#[test]
+fn fractals() {
+	scaled(SumNoise::new([simplex(); 2]));
+	scaled(Octaves::<_, 4>::new(simplex(), 2.0, 0.5));
+	scaled(Octaves::<_, 4>::decorrelated(simplex(), 2.0, 0.5));
+	scaled(Billow::<_, 4>::new(simplex(), 2.0, 0.5));
+	scaled(RidgedMulti::<_, 4>::new(simplex(), 2.0, 0.5, 1.0, 2.0));
+	scaled(HybridMulti::<_, 4>::new(simplex(), 2.0, 0.5, 0.7));
+	scaled(HeteroMulti::<_, 4>::new(simplex(), 2.0, 0.5, 0.7));
+	#[cfg(feature = "alloc")]
+	scaled(DynSumNoise::new(vec![simplex(); 2]));
+	#[cfg(feature = "alloc")]
+	scaled(DynOctaves::new(simplex(), 4, 2.0, 0.5));
+}
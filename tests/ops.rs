@@ -0,0 +1,69 @@
+//! The arithmetic operators must build the same noise functions as the combinators they stand for.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, NoiseDerivative, Simplex, Perlin, Octaves, AddNoise, SubNoise, MulNoise, NegNoise, ScaleValue};
+use sized_matrix::Vector;
+use common::SEED;
+
+fn point() -> Vector<f64, 2> {
+	Vector::vector([1.7, -0.3])
+}
+
+#[test]
+fn operators() {
+	let a = Simplex::new();
+	let b = Octaves::<_, 3>::new(Perlin::new(), 0.5, 0.5);
+	let x = point();
+	
+	assert_eq!((a + b).seed(SEED).noise(x), AddNoise::new(a, b).seed(SEED).noise(x));
+	assert_eq!((a - b).seed(SEED).noise(x), SubNoise::new(a, b).seed(SEED).noise(x));
+	assert_eq!((a * b).seed(SEED).noise(x), MulNoise::new(a, b).seed(SEED).noise(x));
+	assert_eq!((-a).seed(SEED).noise(x), NegNoise::new(a).seed(SEED).noise(x));
+	assert_eq!((a * 0.5).seed(SEED).noise(x), ScaleValue::new(a, 0.5).seed(SEED).noise(x));
+	assert_eq!((a * 0.5).seed(SEED).noise(x), a.seed(SEED).noise(x) * 0.5);
+}
+
+#[test]
+fn scale_f32() {
+	let a = Simplex::<f32>::new();
+	let x = Vector::<f32, 2>::vector([1.7, -0.3]);
+	
+	assert_eq!((a * 0.5).seed(SEED).noise(x), a.seed(SEED).noise(x) * 0.5);
+	assert_eq!((Perlin::<f32>::new() * 2.).seed(SEED).noise(x), Perlin::<f32>::new().seed(SEED).noise(x) * 2.);
+}
+
+#[test]
+fn scale_derivative() {
+	let a = Simplex::new().seed(SEED);
+	let (value, derivative) = (a * 0.5).noise_derivative(point());
+	let (inner_value, inner_derivative) = a.noise_derivative(point());
+	
+	assert_eq!(value, inner_value * 0.5);
+	assert_eq!(derivative, inner_derivative * 0.5);
+}
+
+#[test]
+fn seeded_operators() {
+	let a = Simplex::new().seed(1);
+	let b = Perlin::new().seed(2);
+	let x = point();
+	
+	assert_eq!((a + b).noise(x), a.noise(x) + b.noise(x));
+	assert_eq!((a * b - a).noise(x), a.noise(x) * b.noise(x) - a.noise(x));
+}
+
+#[test]
+fn mul_derivative() {
+	let noise = (Simplex::new() * Simplex::new()).seed(SEED);
+	let x = point();
+	let (value, derivative) = noise.noise_derivative(x);
+	assert!((value - noise.noise(x)).abs() < 1e-12);
+	
+	let h = 1e-6;
+	for i in 0..2 {
+		let offset = Vector::<f64, 2>::vector([(i == 0) as u8 as f64 * h, (i == 1) as u8 as f64 * h]);
+		let estimate = (noise.noise(x + offset) - noise.noise(x - offset)) / (2. * h);
+		assert!((estimate - derivative[i]).abs() < 1e-4, "{} vs {}", estimate, derivative[i]);
+	}
+}
@@ -0,0 +1,39 @@
+//! Periodic noise must repeat every period along each axis.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Periodic, Simplex, Perlin, OpenSimplex2S, OpenSimplexOrientation};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+const TOLERANCE: f64 = 1e-9;
+
+fn check_period<const N: usize>(noise: impl NoiseDomain<Vector<f64, N>, Value = f64>, period: [f64; N]) {
+	for pos in points::<N>(100) {
+		let value = noise.noise(pos);
+		for axis in 0..N {
+			let shifted = Vector::vector(core::array::from_fn(|j| if j == axis { pos[j] + period[j] } else { pos[j] }));
+			assert!((noise.noise(shifted) - value).abs() < TOLERANCE, "axis {} at {:?}", axis, pos);
+		}
+	}
+}
+
+#[test]
+fn periodic_1d() {
+	check_period(Periodic::new(Perlin::new(), Vector::vector([3.5])).seed(SEED), [3.5]);
+	check_period(Periodic::new(Simplex::new(), Vector::vector([8.])).seed(SEED), [8.]);
+	
+	let noise = Periodic::<_, 1>::new(Simplex::new(), Vector::vector([8.])).seed(SEED);
+	assert!((noise.noise(1.25) - noise.noise(-6.75)).abs() < TOLERANCE);
+}
+
+#[test]
+fn periodic_2d() {
+	check_period(Periodic::new(Perlin::new(), Vector::vector([4., 6.5])).seed(SEED), [4., 6.5]);
+	check_period(Periodic::new(OpenSimplex2S::new(OpenSimplexOrientation::Standard), Vector::vector([5., 3.])).seed(SEED), [5., 3.]);
+}
+
+#[test]
+fn periodic_3d() {
+	check_period(Periodic::new(Simplex::new(), Vector::vector([4., 5., 6.])).seed(SEED), [4., 5., 6.]);
+}
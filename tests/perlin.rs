@@ -0,0 +1,27 @@
+//! Perlin noise must stay within [-1, 1] and be zero at every lattice point.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Perlin};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+fn check<const N: usize>() where Perlin: NoiseDomain<Vector<f64, N>, Value = f64> {
+	let perlin = Perlin::new().seed(SEED);
+	let max = points::<N>(20000).map(|point| perlin.noise(point).abs()).fold(0., f64::max);
+	assert!(max <= 1., "{}D reached {}", N, max);
+	assert!(max >= 0.4, "{}D only reached {}", N, max);
+	
+	for point in points::<N>(200) {
+		let lattice = Vector::vector(core::array::from_fn(|i| point[i].floor()));
+		assert_eq!(perlin.noise(lattice), 0., "at {:?}", lattice);
+	}
+}
+
+#[test]
+fn perlin() {
+	check::<1>();
+	check::<2>();
+	check::<3>();
+	check::<4>();
+}
@@ -0,0 +1,42 @@
+//! 1D noise on plain scalars must match the same noise on 1-dimensional vectors, including through the combinators.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Float, Simplex, Perlin, ValueNoise, Interpolation, ScaleNoise, Octaves};
+use sized_matrix::Vector;
+use common::SEED;
+
+use core::fmt::Debug;
+
+fn check<T: Float + Debug>(noise: impl NoiseDomain<T, Value = T> + NoiseDomain<Vector<T, 1>>) {
+	for i in -200..200 {
+		let x = T::from_f64(i as f64 * 0.137);
+		assert_eq!(NoiseDomain::<T>::noise(&noise, x), NoiseDomain::<Vector<T, 1>>::noise(&noise, Vector::vector([x])), "at {:?}", x);
+	}
+}
+
+#[test]
+fn scalar_f64() {
+	check::<f64>(Simplex::new().seed(SEED));
+	check::<f64>(Perlin::new().seed(SEED));
+	check::<f64>(ValueNoise::new(Interpolation::Cubic).seed(SEED));
+}
+
+#[test]
+fn scalar_f32() {
+	check::<f32>(Simplex::<f32>::new().seed(SEED));
+	check::<f32>(Perlin::<f32>::new().seed(SEED));
+	check::<f32>(ValueNoise::new_f32(Interpolation::Cubic).seed(SEED));
+}
+
+#[test]
+fn scalar_combinators() {
+	check::<f64>(ScaleNoise::new(Perlin::new(), 2.5, 0.5).seed(SEED));
+	check::<f64>(Octaves::<_, 4>::new(Simplex::new(), 2.0, 0.5).seed(SEED));
+	check::<f32>(Octaves::<_, 4, f32>::new(ValueNoise::new_f32(Interpolation::Quintic), 2.0, 0.5).seed(SEED));
+	
+	let perlin = Perlin::new().seed(SEED);
+	let octaves = Octaves::<_, 3>::new(Perlin::new(), 2.0, 0.5).seed(SEED);
+	assert_eq!(ScaleNoise::new(Perlin::new(), 2.5, 0.5).seed(SEED).noise(1.3), perlin.noise(1.3 * 2.5) * 0.5);
+	assert!(octaves.noise(0.7) != perlin.noise(0.7));
+}
@@ -0,0 +1,78 @@
+//! The value-shaping combinators must keep their outputs within the ranges they promise.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Simplex, Octaves, Constant, Clamp, Abs, Pow, Terrace, Curve};
+use common::{SEED, points};
+
+#[test]
+fn clamp_abs_pow() {
+	let base = Octaves::<_, 4>::new(Simplex::new(), 0.5, 0.5);
+	let clamp = Clamp::new(base, -0.2, 0.3).seed(SEED);
+	let abs = Abs::new(base).seed(SEED);
+	let pow = Pow::new(Abs::new(base), 2.).seed(SEED);
+	let base = base.seed(SEED);
+	for x in points::<2>(400) {
+		let value = base.noise(x);
+		assert_eq!(clamp.noise(x), value.clamp(-0.2, 0.3));
+		assert_eq!(abs.noise(x), value.abs());
+		assert!((pow.noise(x) - value * value).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn terrace() {
+	let steps = [0.5, -1., 0., 1.];
+	for &invert in &[false, true] {
+		for &step in &steps {
+			assert_eq!(Terrace::new(Constant::new(step), steps, invert).noise(0.), step);
+		}
+		assert_eq!(Terrace::new(Constant::new(-3.), steps, invert).noise(0.), -1.);
+		assert_eq!(Terrace::new(Constant::new(3.), steps, invert).noise(0.), 1.);
+	}
+	
+	assert_eq!(Terrace::new(Constant::new(0.25), steps, false).noise(0.), 0.125);
+	assert_eq!(Terrace::new(Constant::new(0.25), steps, true).noise(0.), 0.375);
+}
+
+#[test]
+fn curve() {
+	let control = [(-1., -1.), (-0.5, -0.9), (0., 0.), (0.2, 0.05), (1., 1.)];
+	for &(input, output) in &control {
+		assert_eq!(Curve::new(Constant::new(input), control).noise(0.), output);
+	}
+	
+	let mut last = -1.;
+	for i in 0..=1000 {
+		let value = Curve::new(Constant::new(i as f64 / 500. - 1.), control).noise(0.);
+		assert!(value >= last, "curve decreased at {}", i);
+		last = value;
+	}
+	
+	let noise = Curve::new(Simplex::new(), control).seed(SEED);
+	assert!(points::<2>(400).all(|x| (-1. ..=1.).contains(&noise.noise(x))));
+}
+
+#[test]
+#[should_panic]
+fn terrace_one_point() {
+	Terrace::new(Simplex::<f64>::new(), [0.5], false);
+}
+
+#[test]
+#[should_panic]
+fn terrace_nan_point() {
+	Terrace::new(Simplex::<f64>::new(), [0., f64::NAN, 1.], false);
+}
+
+#[test]
+#[should_panic]
+fn curve_duplicate_inputs() {
+	Curve::new(Simplex::<f64>::new(), [(-1., -1.), (0., 0.), (0., 0.5), (1., 1.)]);
+}
+
+#[test]
+#[should_panic]
+fn clamp_min_above_max() {
+	Clamp::new(Simplex::<f64>::new(), 0.5, -0.5);
+}
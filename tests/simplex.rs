@@ -0,0 +1,23 @@
+//! The empirical scale factors must keep simplex noise roughly within [-1, 1] in every number of dimensions.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Simplex};
+use common::{SEED, points};
+
+fn check_range<const N: usize>() {
+	let simplex = Simplex::new().seed(SEED);
+	let max = points::<N>(20000).map(|point| simplex.noise(point).abs()).fold(0., f64::max);
+	assert!(max <= 1.1, "{}D reached {}", N, max);
+	assert!(max >= 0.6, "{}D only reached {}", N, max);
+}
+
+#[test]
+fn simplex_range() {
+	check_range::<1>();
+	check_range::<2>();
+	check_range::<3>();
+	check_range::<4>();
+	check_range::<5>();
+	check_range::<6>();
+}
@@ -0,0 +1,33 @@
+//! Value noise must pass through the lattice values exactly, whichever curve interpolates between them.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, HashNoise, ToFloat, ValueNoise, Interpolation};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+const INTERPOLATIONS: [Interpolation; 3] = [Interpolation::Linear, Interpolation::Cubic, Interpolation::Quintic];
+
+fn check<const N: usize>() where
+	ValueNoise<ToFloat<HashNoise>>: NoiseDomain<Vector<f64, N>, Value = f64>,
+	ToFloat<HashNoise>: NoiseDomain<Vector<i64, N>, Value = f64>,
+{
+	let lattice_values = ToFloat::new(HashNoise::new()).seed(SEED);
+	for interpolation in INTERPOLATIONS {
+		let noise = ValueNoise::new(interpolation).seed(SEED);
+		for point in points::<N>(200) {
+			let lattice = Vector::vector(core::array::from_fn(|i| point[i].floor() as i64));
+			let expected = 2. * lattice_values.noise(lattice) - 1.;
+			assert_eq!(noise.noise(Vector::vector(core::array::from_fn(|i| lattice[i] as f64))), expected, "{:?} at {:?}", interpolation, lattice);
+			assert!((-1. ..1.).contains(&noise.noise(point)));
+		}
+	}
+}
+
+#[test]
+fn lattice_values() {
+	check::<1>();
+	check::<2>();
+	check::<3>();
+	check::<4>();
+}
@@ -0,0 +1,23 @@
+//! Domain warping must offset the input by the scaled offset noise, seeding both like `AddNoise`.
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, Simplex, Perlin, Octaves, CurlNoise, Warp, noise::SplitSeed};
+use common::{SEED, points};
+
+#[test]
+fn warp() {
+	let warp = Warp::new(Perlin::new(), CurlNoise::<_, 3>::new(Simplex::new()), 0.4).seed(SEED);
+	let inner = Perlin::new().seed(SEED.split(0));
+	let offset = CurlNoise::<_, 3>::new(Simplex::new()).seed(SEED.split(1));
+	for x in points::<3>(200) {
+		assert_eq!(warp.noise(x), inner.noise(x + offset.noise(x) * 0.4));
+	}
+	
+	let warp = Warp::new(Simplex::new(), CurlNoise::<_, 1>::new(Octaves::<_, 3>::new(Simplex::new(), 2., 0.5)), 2.).seed(SEED);
+	let inner = Simplex::new().seed(SEED.split(0));
+	let offset = CurlNoise::<_, 1>::new(Octaves::<_, 3>::new(Simplex::new(), 2., 0.5)).seed(SEED.split(1));
+	for x in points::<2>(200) {
+		assert_eq!(warp.noise(x), inner.noise(x + offset.noise(x) * 2.));
+	}
+}
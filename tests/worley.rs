@@ -0,0 +1,53 @@
+//! Worley noise must order its feature distances, measure them with the chosen metric, and give cell values in [0, 1).
+
+mod common;
+
+use noise_fn::{Seedable, NoiseDomain, HashNoise, Worley, WorleyDistance, WorleyReturn};
+use sized_matrix::Vector;
+use common::{SEED, points};
+
+const DISTANCES: [WorleyDistance; 3] = [WorleyDistance::Euclidean, WorleyDistance::Manhattan, WorleyDistance::Chebyshev];
+
+fn check_order<const N: usize>() where Worley<HashNoise>: NoiseDomain<Vector<f64, N>, Value = f64> {
+	for distance in DISTANCES {
+		let f1 = Worley::new(distance, WorleyReturn::F1, 1.).seed(SEED);
+		let f2 = Worley::new(distance, WorleyReturn::F2, 1.).seed(SEED);
+		let difference = Worley::new(distance, WorleyReturn::F2MinusF1, 1.).seed(SEED);
+		let cell = Worley::new(distance, WorleyReturn::CellValue, 1.).seed(SEED);
+		for x in points::<N>(200) {
+			assert!(f1.noise(x) >= 0. && f1.noise(x) <= f2.noise(x), "{:?} at {:?}", distance, x);
+			assert_eq!(difference.noise(x), f2.noise(x) - f1.noise(x));
+			assert!(difference.noise(x) >= 0.);
+			assert!((0. ..1.).contains(&cell.noise(x)));
+		}
+	}
+}
+
+#[test]
+fn order() {
+	check_order::<2>();
+	check_order::<3>();
+	check_order::<4>();
+}
+
+#[test]
+fn metrics() {
+	// Without jitter the closest feature point is the centre of the cell containing the point
+	let euclidean = Worley::new(WorleyDistance::Euclidean, WorleyReturn::F1, 0.).seed(SEED);
+	let manhattan = Worley::new(WorleyDistance::Manhattan, WorleyReturn::F1, 0.).seed(SEED);
+	let chebyshev = Worley::new(WorleyDistance::Chebyshev, WorleyReturn::F1, 0.).seed(SEED);
+	for x in points::<3>(500) {
+		let delta: [f64; 3] = core::array::from_fn(|i| (x[i].floor() + 0.5 - x[i]).abs());
+		assert!((euclidean.noise(x) - delta.iter().map(|d| d * d).sum::<f64>().sqrt()).abs() < 1e-12, "at {:?}", x);
+		assert!((manhattan.noise(x) - delta.iter().sum::<f64>()).abs() < 1e-12, "at {:?}", x);
+		assert!((chebyshev.noise(x) - delta.iter().copied().fold(0., f64::max)).abs() < 1e-12, "at {:?}", x);
+	}
+	
+	// Chebyshev distances are never longer than Euclidean ones, which are never longer than Manhattan ones
+	let euclidean = Worley::new(WorleyDistance::Euclidean, WorleyReturn::F1, 1.).seed(SEED);
+	let manhattan = Worley::new(WorleyDistance::Manhattan, WorleyReturn::F1, 1.).seed(SEED);
+	let chebyshev = Worley::new(WorleyDistance::Chebyshev, WorleyReturn::F1, 1.).seed(SEED);
+	for x in points::<2>(500) {
+		assert!(chebyshev.noise(x) <= euclidean.noise(x) && euclidean.noise(x) <= manhattan.noise(x), "at {:?}", x);
+	}
+}